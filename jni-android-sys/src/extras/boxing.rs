@@ -0,0 +1,87 @@
+#![cfg(any(feature = "all", feature = "java-lang-Object"))]
+
+use super::java;
+
+use jni_glue::*;
+use jni_glue::jni_sys::JNI_FALSE;
+
+
+
+/// Rust primitives that can be boxed into (and unboxed from) their java.lang wrapper types, such as `i32` and
+/// java.lang.Integer.  Useful for collections and other APIs that only accept objects.
+///
+/// ```rust,no_run
+/// # use jni_android_sys::*;
+/// # fn example(env: &jni_glue::Env) {
+/// let boxed   = 42i32.to_java(env);               // Local<java::lang::Integer>
+/// let object  : &java::lang::Object = &boxed;
+/// assert_eq!(object.unbox::<i32>(), Some(42));
+/// assert_eq!(object.unbox::<i64>(), None);        // Not a java.lang.Long
+/// # }
+/// ```
+pub trait JavaBox : Sized {
+    /// The java.lang wrapper type, such as java::lang::Integer.
+    type Boxed : AsValidJObjectAndEnv;
+
+    /// Uses the wrapper type's static valueOf method to box `self`.
+    fn to_java<'env>(self, env: &'env Env) -> Local<'env, Self::Boxed>;
+
+    /// Uses IsInstanceOf + the wrapper type's {type}Value method to unbox `object`, or returns None if `object` isn't an
+    /// instance of the wrapper type.
+    fn from_java(object: &java::lang::Object) -> Option<Self>;
+}
+
+impl java::lang::Object {
+    /// Unbox this into a Rust primitive, or returns None if this isn't an instance of the corresponding java.lang
+    /// wrapper type.  See [JavaBox](trait.JavaBox.html).
+    pub fn unbox<T: JavaBox>(&self) -> Option<T> {
+        T::from_java(self)
+    }
+}
+
+
+
+macro_rules! java_box {
+    ($($rust:ty => $feature:literal $boxed:ident ($class:expr, $value_of:expr, $value:expr, $value_sig:expr) $call:ident |$v:ident| $from_jni:expr;)*) => {$(
+        #[cfg(any(feature = "all", feature = $feature))]
+        impl JavaBox for $rust {
+            type Boxed = java::lang::$boxed;
+
+            fn to_java<'env>(self, env: &'env Env) -> Local<'env, Self::Boxed> {
+                unsafe {
+                    // Cached per VM, so these stay valid even if the VM is recreated (e.g. with jni_glue::OwnedVM.)
+                    let (class, value_of) = env.require_cached_class_static_method($class, "valueOf\0", $value_of);
+                    let jnienv = env.as_jni_env();
+                    let args = [self.as_jvalue()];
+                    let object = (**jnienv).CallStaticObjectMethodA.unwrap()(jnienv, class, value_of, args.as_ptr());
+                    assert!(!object.is_null()); // Only sane failure here is an OOM
+                    Local::from_env_object(jnienv, object)
+                }
+            }
+
+            fn from_java(object: &java::lang::Object) -> Option<Self> {
+                unsafe {
+                    let env = Env::from_ptr(object.0.env);
+                    let (class, value) = env.require_cached_class_method($class, $value, $value_sig);
+                    let jnienv = env.as_jni_env();
+                    if (**jnienv).IsInstanceOf.unwrap()(jnienv, object.0.object, class) == JNI_FALSE {
+                        return None;
+                    }
+                    let $v = (**jnienv).$call.unwrap()(jnienv, object.0.object, value, [].as_ptr());
+                    Some($from_jni)
+                }
+            }
+        }
+    )*};
+}
+
+java_box! {
+    bool    => "java-lang-Boolean"      Boolean     ("java/lang/Boolean\0",     "(Z)Ljava/lang/Boolean;\0",     "booleanValue\0",   "()Z\0") CallBooleanMethodA |v| v != JNI_FALSE;
+    i8      => "java-lang-Byte"         Byte        ("java/lang/Byte\0",        "(B)Ljava/lang/Byte;\0",        "byteValue\0",      "()B\0") CallByteMethodA    |v| v;
+    jchar   => "java-lang-Character"    Character   ("java/lang/Character\0",   "(C)Ljava/lang/Character;\0",   "charValue\0",      "()C\0") CallCharMethodA    |v| jni_glue::jchar(v);
+    i16     => "java-lang-Short"        Short       ("java/lang/Short\0",       "(S)Ljava/lang/Short;\0",       "shortValue\0",     "()S\0") CallShortMethodA   |v| v;
+    i32     => "java-lang-Integer"      Integer     ("java/lang/Integer\0",     "(I)Ljava/lang/Integer;\0",     "intValue\0",       "()I\0") CallIntMethodA     |v| v;
+    i64     => "java-lang-Long"         Long        ("java/lang/Long\0",        "(J)Ljava/lang/Long;\0",        "longValue\0",      "()J\0") CallLongMethodA    |v| v;
+    f32     => "java-lang-Float"        Float       ("java/lang/Float\0",       "(F)Ljava/lang/Float;\0",       "floatValue\0",     "()F\0") CallFloatMethodA   |v| v;
+    f64     => "java-lang-Double"       Double      ("java/lang/Double\0",      "(D)Ljava/lang/Double;\0",      "doubleValue\0",    "()D\0") CallDoubleMethodA  |v| v;
+}
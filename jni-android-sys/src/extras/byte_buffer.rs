@@ -0,0 +1,75 @@
+#![cfg(any(feature = "all", feature = "java-nio-ByteBuffer"))]
+
+use super::java;
+
+use jni_glue::*;
+
+use std::os::raw::c_void;
+use std::slice;
+
+
+
+impl java::nio::ByteBuffer {
+    /// Create a new local direct ByteBuffer referencing `data` via env.NewDirectByteBuffer, without copying.
+    ///
+    /// # Safety
+    ///
+    /// Java will happily read and write `data` for as long as *any* reference to the ByteBuffer - or anything derived
+    /// from it, such as a slice() or duplicate() - is alive.  The returned `Local` can't outlive `data`, but you must
+    /// also ensure no `Global`s or Java code retain the buffer past then.  Prefer [new_direct_static] when possible.
+    ///
+    /// [new_direct_static]:    #method.new_direct_static
+    pub unsafe fn new_direct<'env>(env: &'env Env, data: &'env mut [u8]) -> Local<'env, Self> {
+        Self::new_direct_impl(env, data)
+    }
+
+    /// Create a new local direct ByteBuffer referencing `data` via env.NewDirectByteBuffer, without copying.
+    ///
+    /// Since `data` is never freed, this is safe no matter how long Java holds onto the buffer.
+    pub fn new_direct_static<'env>(env: &'env Env, data: &'static mut [u8]) -> Local<'env, Self> {
+        unsafe { Self::new_direct_impl(env, data) }
+    }
+
+    unsafe fn new_direct_impl<'env>(env: &'env Env, data: &mut [u8]) -> Local<'env, Self> {
+        assert!(data.len() <= std::i32::MAX as usize, "Java ByteBuffers are limited to 2 GiB");
+        let buffer = env.new_direct_byte_buffer(data.as_mut_ptr() as *mut c_void, data.len() as jni_sys::jlong);
+        assert!(!buffer.is_null()); // Only sane failures here are an OOM, or a JVM without direct buffer support
+        Local::from_env_object(env.as_jni_env() as *const _, buffer)
+    }
+
+    /// Get the memory backing a direct ByteBuffer via env.GetDirectBufferAddress/Capacity, or None if this isn't a
+    /// direct buffer.
+    ///
+    /// # Safety
+    ///
+    /// Java code may still modify the buffer's contents, so you must ensure nothing else writes to it while the
+    /// returned slice is alive.
+    pub unsafe fn direct_slice(&self) -> Option<&[u8]> {
+        self.direct_parts().map(|(address, capacity)| slice::from_raw_parts(address, capacity))
+    }
+
+    /// Get the memory backing a direct ByteBuffer via env.GetDirectBufferAddress/Capacity, or None if this isn't a
+    /// direct buffer.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure nothing else - Rust or Java - reads or writes the buffer's contents while the returned slice is
+    /// alive.  This includes other calls to [direct_slice] and [direct_slice_mut].
+    ///
+    /// [direct_slice]:     #method.direct_slice
+    /// [direct_slice_mut]: #method.direct_slice_mut
+    pub unsafe fn direct_slice_mut(&self) -> Option<&mut [u8]> {
+        self.direct_parts().map(|(address, capacity)| slice::from_raw_parts_mut(address, capacity))
+    }
+
+    unsafe fn direct_parts(&self) -> Option<(*mut u8, usize)> {
+        let env = Env::from_ptr(self.0.env);
+        let address = env.get_direct_buffer_address(self.0.object);
+        let capacity = env.get_direct_buffer_capacity(self.0.object);
+        if address.is_null() || capacity < 0 {
+            None
+        } else {
+            Some((address as *mut u8, capacity as usize))
+        }
+    }
+}
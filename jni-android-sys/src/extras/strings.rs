@@ -12,10 +12,16 @@ use std::char::DecodeUtf16Error;
 impl java::lang::String {
     /// Create new local string from an Env + AsRef<str>
     pub fn from_env_str<'env, S: AsRef<str>>(env: &'env Env, string: S) -> Local<'env, Self> {
-        let chars = string.as_ref().encode_utf16().collect::<Vec<_>>();
+        let string = env.new_string_from_str(string.as_ref());
+        unsafe { Local::from_env_object(env.as_jni_env() as *const _, string) }
+    }
+
+    /// Create new local string from an Env + AsRef<JavaStr>, without loss - even if it contains unpaired surrogates.
+    pub fn from_env_java_str<'env, S: AsRef<JavaStr>>(env: &'env Env, string: S) -> Local<'env, Self> {
+        let chars = string.as_ref().to_jchars();
 
         let string = unsafe { env.new_string(
-            chars.as_ptr() as *const jchar,
+            chars.as_ptr(),
             chars.len() as jni_sys::jsize,
         ) };
 
@@ -43,6 +49,9 @@ impl java::lang::String {
     /// [String]:                   https://doc.rust-lang.org/std/string/struct.String.html
     /// [REPLACEMENT_CHARACTER]:    https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html
     pub fn to_string_lossy(&self) -> String { self.string_chars().to_string_lossy() }
+
+    /// Returns a new `JavaString` without loss, even if it contains unpaired surrogates.
+    pub fn to_java_string(&self) -> JavaString { self.string_chars().to_java_string() }
 }
 
 // OsString doesn't implement Display, so neither does java::lang::String.
@@ -5,6 +5,10 @@ use std::fmt::{self, Debug, Formatter};
 
 
 
+/// An owned, Send + Sync, std::error::Error wrapper around a java.lang.Throwable.  Generated methods return
+/// `Result<_, Local<'env, java::lang::Throwable>>`, which `?` will convert into this.
+pub type JavaException = jni_glue::JavaException<java::lang::Throwable>;
+
 impl ThrowableType for java::lang::Throwable {}
 
 impl Debug for java::lang::Throwable {
@@ -65,9 +69,9 @@ impl Debug for java::lang::Throwable {
                 }
             }
 
+            fmt_causes(f, self, 0)?;
+
             // Consider also dumping:
-            // API level 1+:
-            //      getCause()
             // API level 19+:
             //      getSuppressed()
         }
@@ -75,3 +79,20 @@ impl Debug for java::lang::Throwable {
         Ok(())
     }
 }
+
+#[cfg(any(feature = "all", feature = "java-lang-String"))]
+fn fmt_causes(f: &mut Formatter<'_>, throwable: &java::lang::Throwable, depth: usize) -> fmt::Result {
+    if depth >= 32 { return Ok(()); } // Guard against pathological (or cyclic!) cause chains.
+    match throwable.getCause() {
+        Err(_)          => writeln!(f, "    getCause:              N/A (threw an exception!)"),
+        Ok(None)        => Ok(()),
+        Ok(Some(cause)) => {
+            match cause.toString() {
+                Ok(Some(string))    => writeln!(f, "    getCause:              {}", string.to_string_lossy())?,
+                Ok(None)            => writeln!(f, "    getCause:              N/A (toString returned null)")?,
+                Err(_)              => writeln!(f, "    getCause:              N/A (toString threw an exception!)")?,
+            }
+            fmt_causes(f, &cause, depth + 1)
+        },
+    }
+}
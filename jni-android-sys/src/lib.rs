@@ -40,6 +40,13 @@ cfg_if! {if #[cfg(any(target_os = "android", feature = "force-define", all(featu
 #[cfg(any(target_os = "android", feature = "force-define", all(feature = "force-define-x86_64-unknown-linux-gnu", target_arch = "x86_64", target_vendor = "unknown", target_os = "linux", target_env = "gnu")))] mod extras {
     use super::*;
 
+    mod boxing;
+    mod byte_buffer;
     mod strings;
     mod throwable;
+
+    pub use boxing::*;
+    pub use throwable::*;
 }
+
+#[cfg(any(target_os = "android", feature = "force-define", all(feature = "force-define-x86_64-unknown-linux-gnu", target_arch = "x86_64", target_vendor = "unknown", target_os = "linux", target_env = "gnu")))] pub use extras::*;
@@ -0,0 +1,522 @@
+// WARNING:  This file was autogenerated by jni-bindgen.  Any changes to this file may be lost!!!
+
+#[allow(unused_imports)] #[macro_use(__jni_bindgen)] extern crate jni_glue;
+#[doc(hidden)] use jni_glue as __jni_bindgen;
+
+
+
+#[allow(non_camel_case_types)]   // We map Java inner classes to Outer_Inner
+#[allow(dead_code)]              // We generate structs for private Java types too, just in case.
+#[allow(deprecated)]             // We're generating deprecated types/methods
+#[allow(non_upper_case_globals)] // We might be generating Java style fields/methods
+#[allow(non_snake_case)]         // We might be generating Java style fields/methods
+pub mod com {
+    #[allow(unused_imports)] use super::__jni_bindgen;
+
+    pub mod example {
+        #[allow(unused_imports)] use super::__jni_bindgen;
+
+        #[cfg(any(feature = "all", feature = "com-example-FormatException"))]
+        __jni_bindgen! {
+            /// public class com/example/FormatException
+            ///
+            /// Required feature: "com-example-FormatException"
+            public class FormatException ("com/example/FormatException") extends crate::java::lang::Throwable {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::FormatException>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/FormatException", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/FormatException\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Listener"))]
+        __jni_bindgen! {
+            /// public interface com/example/Listener
+            ///
+            /// Required feature: "com-example-Listener"
+            public interface Listener ("com/example/Listener") extends crate::java::lang::Object {
+
+                /// onValue
+                pub fn on_value_int<'env>(&'env self, arg0: i32) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "onValue", .descriptor == "(I)I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "onValue\0", "(I)I\0");
+                        __jni_env.call_int_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// onValue
+                pub fn on_value_long<'env>(&'env self, arg0: i64) -> __jni_bindgen::std::result::Result<i64, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "onValue", .descriptor == "(J)J"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "onValue\0", "(J)J\0");
+                        __jni_env.call_long_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-Object", "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-Object", feature = "java-lang-String")))]
+                pub fn describe<'env>(&'env self, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::Object>>) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "describe", .descriptor == "(Ljava/lang/Object;)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "describe\0", "(Ljava/lang/Object;)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// fire
+                pub fn fire<'env>(&'env self) -> __jni_bindgen::std::result::Result<(), __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "fire", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "fire\0", "()V\0");
+                        __jni_env.call_void_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        /// Implement com/example/Listener in Rust, for use with [Listener::new_proxy](struct.Listener.html#method.new_proxy).
+        #[cfg(any(feature = "all", all(feature = "com-example-Listener", feature = "java-lang-Object", feature = "java-lang-String")))]
+        #[allow(clippy::needless_lifetimes)] // 'env is named consistently, even where it could be elided
+        pub trait ListenerProxy : __jni_bindgen::std::marker::Send + __jni_bindgen::std::marker::Sync + 'static {
+
+            /// onValue(I)I
+            fn on_value_int<'env>(&self, env: &'env __jni_bindgen::Env, arg0: i32) -> i32;
+
+            /// onValue(J)J
+            fn on_value_long<'env>(&self, env: &'env __jni_bindgen::Env, arg0: i64) -> i64;
+
+            /// describe(Ljava/lang/Object;)Ljava/lang/String;
+            fn describe<'env>(&self, env: &'env __jni_bindgen::Env, arg0: __jni_bindgen::std::option::Option<__jni_bindgen::Ref<'env, crate::java::lang::Object>>) -> __jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>;
+
+            /// fire()V
+            fn fire<'env>(&self, env: &'env __jni_bindgen::Env);
+        }
+
+        #[cfg(any(feature = "all", all(feature = "com-example-Listener", feature = "java-lang-Object", feature = "java-lang-String")))]
+        impl Listener {
+            /// Implements com/example/Listener by forwarding calls to `imp`, via a java.lang.reflect.Proxy.
+            pub fn new_proxy<'env>(env: &'env __jni_bindgen::Env, imp: impl ListenerProxy) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, Listener>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                __jni_bindgen::Proxy::create(env, move |invocation: &__jni_bindgen::Invocation| {
+                    let env = invocation.env();
+                    match invocation.method_name().as_str() {
+                        "onValue" if invocation.method_descriptor() == "(I)I" => invocation.result(imp.on_value_int(env, invocation.arg(0))),
+                        "onValue" if invocation.method_descriptor() == "(J)J" => invocation.result(imp.on_value_long(env, invocation.arg(0))),
+                        "describe" => invocation.result(imp.describe(env, invocation.arg(0))),
+                        "fire" => { imp.fire(env); invocation.result(()) },
+                        _ => invocation.unsupported(),
+                    }
+                })
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-ParseException"))]
+        __jni_bindgen! {
+            /// public class com/example/ParseException
+            ///
+            /// Required feature: "com-example-ParseException"
+            public class ParseException ("com/example/ParseException") extends crate::java::lang::Throwable {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::ParseException>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/ParseException", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/ParseException\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Parser"))]
+        __jni_bindgen! {
+            /// public class com/example/Parser
+            ///
+            /// Required feature: "com-example-Parser"
+            public class Parser ("com/example/Parser") extends crate::java::lang::Object {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::Parser>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Parser", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Parser\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// parse
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn parse<'env>(&'env self, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::String>>) -> __jni_bindgen::std::result::Result<i32, ParserError<'env>> {
+                    // class.path == "com/example/Parser", java.flags == PUBLIC, .name == "parse", .descriptor == "(Ljava/lang/String;)I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Parser\0", "parse\0", "(Ljava/lang/String;)I\0");
+                        __jni_env.call_int_method_a(self.0.object, __jni_method, __jni_args.as_ptr()).map_err(|e: __jni_bindgen::Local<'env, crate::java::lang::Throwable>| e.into())
+                    }
+                }
+
+                /// format
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn format<'env>(&'env self, arg0: i32) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, ParserError<'env>> {
+                    // class.path == "com/example/Parser", java.flags == PUBLIC, .name == "format", .descriptor == "(I)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Parser\0", "format\0", "(I)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr()).map_err(|e: __jni_bindgen::Local<'env, crate::java::lang::Throwable>| e.into())
+                    }
+                }
+            }
+        }
+
+        /// Exceptions declared by [Parser::parse](struct.Parser.html#method.parse), [Parser::format](struct.Parser.html#method.format).
+        ///
+        /// Converted from a thrown `crate::java::lang::Throwable` by checking each declared exception with `instanceof`, in `throws` order.
+        #[cfg(any(feature = "all", feature = "com-example-Parser"))]
+        pub enum ParserError<'env> {
+            /// com.example.ParseException
+            #[cfg(any(feature = "all", feature = "com-example-ParseException"))]
+            ParseException(__jni_bindgen::Local<'env, crate::com::example::ParseException>),
+            /// com.example.FormatException
+            #[cfg(any(feature = "all", feature = "com-example-FormatException"))]
+            FormatException(__jni_bindgen::Local<'env, crate::com::example::FormatException>),
+            /// Anything else, such as unchecked exceptions.
+            Other(__jni_bindgen::Local<'env, crate::java::lang::Throwable>),
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Parser"))]
+        impl<'env> __jni_bindgen::std::convert::From<__jni_bindgen::Local<'env, crate::java::lang::Throwable>> for ParserError<'env> {
+            fn from(throwable: __jni_bindgen::Local<'env, crate::java::lang::Throwable>) -> Self {
+                #[cfg(any(feature = "all", feature = "com-example-ParseException"))]
+                let throwable = match throwable.try_cast::<crate::com::example::ParseException>() { Ok(e) => return ParserError::ParseException(e), Err(t) => t };
+                #[cfg(any(feature = "all", feature = "com-example-FormatException"))]
+                let throwable = match throwable.try_cast::<crate::com::example::FormatException>() { Ok(e) => return ParserError::FormatException(e), Err(t) => t };
+                ParserError::Other(throwable)
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Parser"))]
+        impl<'env> __jni_bindgen::std::fmt::Debug for ParserError<'env> {
+            fn fmt(&self, f: &mut __jni_bindgen::std::fmt::Formatter<'_>) -> __jni_bindgen::std::fmt::Result {
+                let (variant, throwable) : (&str, &crate::java::lang::Throwable) = match self {
+                    #[cfg(any(feature = "all", feature = "com-example-ParseException"))]
+                    ParserError::ParseException(e) => ("ParseException", e),
+                    #[cfg(any(feature = "all", feature = "com-example-FormatException"))]
+                    ParserError::FormatException(e) => ("FormatException", e),
+                    ParserError::Other(e) => ("Other", e),
+                };
+                f.debug_tuple(variant).field(&__jni_bindgen::ThrowableDetails::of(throwable)).finish()
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-RustLib"))]
+        __jni_bindgen! {
+            /// public class com/example/RustLib
+            ///
+            /// Required feature: "com-example-RustLib"
+            public class RustLib ("com/example/RustLib") extends crate::java::lang::Object {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::RustLib>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// add
+                pub fn add<'env>(__jni_env: &'env __jni_bindgen::Env, arg0: i32, arg1: i32) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | STATIC | NATIVE, .name == "add", .descriptor == "(II)I"
+                    unsafe {
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0), __jni_bindgen::AsJValue::as_jvalue(&arg1)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_static_method("com/example/RustLib\0", "add\0", "(II)I\0");
+                        __jni_env.call_static_int_method_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-Object", "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-Object", feature = "java-lang-String")))]
+                pub fn describe_object<'env>(&'env self, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::Object>>) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | NATIVE, .name == "describe", .descriptor == "(Ljava/lang/Object;)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "describe\0", "(Ljava/lang/Object;)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn describe_int<'env>(&'env self, arg0: i32) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | NATIVE, .name == "describe", .descriptor == "(I)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "describe\0", "(I)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// get_count
+                pub fn get_count<'env>(&'env self) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | NATIVE, .name == "get_count", .descriptor == "()I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "get_count\0", "()I\0");
+                        __jni_env.call_int_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        /// Native methods of com/example/RustLib.  Implement this trait, then export the implementation with `com_example_RustLib_natives!(YourType);`
+        #[cfg(any(feature = "all", all(feature = "com-example-RustLib", feature = "java-lang-Object", feature = "java-lang-String")))]
+        #[allow(clippy::needless_lifetimes)] // 'env is named consistently, even where it could be elided
+        pub trait RustLibNatives {
+
+            /// static native add(II)I
+            fn add<'env>(env: &'env __jni_bindgen::Env, class: __jni_bindgen::jni_sys::jclass, arg0: i32, arg1: i32) -> i32;
+
+            /// native describe(Ljava/lang/Object;)Ljava/lang/String;
+            fn describe_object<'env>(env: &'env __jni_bindgen::Env, this: __jni_bindgen::Ref<'env, crate::com::example::RustLib>, arg0: __jni_bindgen::std::option::Option<__jni_bindgen::Ref<'env, crate::java::lang::Object>>) -> __jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>;
+
+            /// native describe(I)Ljava/lang/String;
+            fn describe_int<'env>(env: &'env __jni_bindgen::Env, this: __jni_bindgen::Ref<'env, crate::com::example::RustLib>, arg0: i32) -> __jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>;
+
+            /// native get_count()I
+            fn get_count<'env>(env: &'env __jni_bindgen::Env, this: __jni_bindgen::Ref<'env, crate::com::example::RustLib>) -> i32;
+        }
+
+        /// Exports `extern "system"` JNI entry points for the native methods of com/example/RustLib, forwarding to `<$imp as crate::com::example::RustLibNatives>`.
+        #[cfg(any(feature = "all", all(feature = "com-example-RustLib", feature = "java-lang-Object", feature = "java-lang-String")))]
+        #[macro_export] macro_rules! com_example_RustLib_natives {
+            ($imp:ty) => {
+                #[doc(hidden)]
+                #[no_mangle]
+                #[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]
+                pub extern "system" fn Java_com_example_RustLib_add<'env>(env: &'env ::jni_glue::Env, class: <::jni_glue::jni_sys::jclass as ::jni_glue::NativeArg<'env>>::Raw, arg0: <i32 as ::jni_glue::NativeArg<'env>>::Raw, arg1: <i32 as ::jni_glue::NativeArg<'env>>::Raw) -> <i32 as ::jni_glue::NativeReturn>::Raw {
+                    ::jni_glue::__jni_native_entry(env, move || unsafe { <$imp as $crate::com::example::RustLibNatives>::add(env, ::jni_glue::NativeArg::from_raw(env, class), ::jni_glue::NativeArg::from_raw(env, arg0), ::jni_glue::NativeArg::from_raw(env, arg1)) })
+                }
+                #[doc(hidden)]
+                #[no_mangle]
+                #[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]
+                pub extern "system" fn Java_com_example_RustLib_describe__Ljava_lang_Object_2<'env>(env: &'env ::jni_glue::Env, this: <::jni_glue::Ref<'env, $crate::com::example::RustLib> as ::jni_glue::NativeArg<'env>>::Raw, arg0: <::jni_glue::std::option::Option<::jni_glue::Ref<'env, $crate::java::lang::Object>> as ::jni_glue::NativeArg<'env>>::Raw) -> <::jni_glue::std::option::Option<::jni_glue::Local<'env, $crate::java::lang::String>> as ::jni_glue::NativeReturn>::Raw {
+                    ::jni_glue::__jni_native_entry(env, move || unsafe { <$imp as $crate::com::example::RustLibNatives>::describe_object(env, ::jni_glue::NativeArg::from_raw(env, this), ::jni_glue::NativeArg::from_raw(env, arg0)) })
+                }
+                #[doc(hidden)]
+                #[no_mangle]
+                #[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]
+                pub extern "system" fn Java_com_example_RustLib_describe__I<'env>(env: &'env ::jni_glue::Env, this: <::jni_glue::Ref<'env, $crate::com::example::RustLib> as ::jni_glue::NativeArg<'env>>::Raw, arg0: <i32 as ::jni_glue::NativeArg<'env>>::Raw) -> <::jni_glue::std::option::Option<::jni_glue::Local<'env, $crate::java::lang::String>> as ::jni_glue::NativeReturn>::Raw {
+                    ::jni_glue::__jni_native_entry(env, move || unsafe { <$imp as $crate::com::example::RustLibNatives>::describe_int(env, ::jni_glue::NativeArg::from_raw(env, this), ::jni_glue::NativeArg::from_raw(env, arg0)) })
+                }
+                #[doc(hidden)]
+                #[no_mangle]
+                #[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]
+                pub extern "system" fn Java_com_example_RustLib_get_1count<'env>(env: &'env ::jni_glue::Env, this: <::jni_glue::Ref<'env, $crate::com::example::RustLib> as ::jni_glue::NativeArg<'env>>::Raw) -> <i32 as ::jni_glue::NativeReturn>::Raw {
+                    ::jni_glue::__jni_native_entry(env, move || unsafe { <$imp as $crate::com::example::RustLibNatives>::get_count(env, ::jni_glue::NativeArg::from_raw(env, this)) })
+                }
+            };
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Settings"))]
+        __jni_bindgen! {
+            /// public class com/example/Settings
+            ///
+            /// Required feature: "com-example-Settings"
+            public class Settings ("com/example/Settings") extends crate::java::lang::Object {
+
+                /// <init>
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::String>>) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::Settings>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC, .name == "<init>", .descriptor == "(Ljava/lang/String;)V"
+                    unsafe {
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Settings\0", "<init>\0", "(Ljava/lang/String;)V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// getName
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn get_name<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC | STATIC, .name == "getName", .descriptor == "()Ljava/lang/String;"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_static_method("com/example/Settings\0", "getName\0", "()Ljava/lang/String;\0");
+                        __jni_env.call_static_object_method_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// setName
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn set_name<'env>(__jni_env: &'env __jni_bindgen::Env, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::String>>) -> __jni_bindgen::std::result::Result<(), __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC | STATIC, .name == "setName", .descriptor == "(Ljava/lang/String;)V"
+                    unsafe {
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_static_method("com/example/Settings\0", "setName\0", "(Ljava/lang/String;)V\0");
+                        __jni_env.call_static_void_method_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn describe<'env>(&'env self, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::String>>, arg1: i32) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC, .name == "describe", .descriptor == "(Ljava/lang/String;I)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into()), __jni_bindgen::AsJValue::as_jvalue(&arg1)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Settings\0", "describe\0", "(Ljava/lang/String;I)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// **get** public static count
+                pub fn count<'env>(env: &'env __jni_bindgen::Env) -> i32 {
+                    unsafe {
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "count\0", "I\0");
+                        env.get_static_int_field(__jni_class, __jni_field)
+                    }
+                }
+
+                /// **set** public static count
+                pub fn set_count<'env>(env: &'env __jni_bindgen::Env, value: i32) {
+                    unsafe {
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "count\0", "I\0");
+                        env.set_static_int_field(__jni_class, __jni_field, value)
+                    }
+                }
+
+                /// **get** public static label
+                ///
+                /// Required feature: "java-lang-String"
+                #[cfg(any(feature = "all", feature = "java-lang-String"))]
+                pub fn label<'env>(env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>> {
+                    unsafe {
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "label\0", "Ljava/lang/String;\0");
+                        env.get_static_object_field(__jni_class, __jni_field)
+                    }
+                }
+
+                /// **set** public static label
+                ///
+                /// Required feature: "java-lang-String"
+                #[cfg(any(feature = "all", feature = "java-lang-String"))]
+                pub fn set_label<'env, 'obj>(env: &'env __jni_bindgen::Env, value: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'obj crate::java::lang::String>>) {
+                    unsafe {
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "label\0", "Ljava/lang/String;\0");
+                        env.set_static_object_field(__jni_class, __jni_field, value)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]   // We map Java inner classes to Outer_Inner
+#[allow(dead_code)]              // We generate structs for private Java types too, just in case.
+#[allow(deprecated)]             // We're generating deprecated types/methods
+#[allow(non_upper_case_globals)] // We might be generating Java style fields/methods
+#[allow(non_snake_case)]         // We might be generating Java style fields/methods
+pub mod java {
+    #[allow(unused_imports)] use super::__jni_bindgen;
+
+    pub mod lang {
+        #[allow(unused_imports)] use super::__jni_bindgen;
+
+        #[cfg(any(feature = "all", feature = "java-lang-Object"))]
+        __jni_bindgen! {
+            /// public class java/lang/Object
+            ///
+            /// Required feature: "java-lang-Object"
+            public class Object ("java/lang/Object") extends () {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::java::lang::Object>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "java/lang/Object", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("java/lang/Object\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "java-lang-String"))]
+        __jni_bindgen! {
+            /// public final class java/lang/String
+            ///
+            /// Required feature: "java-lang-String"
+            public final class String ("java/lang/String") extends crate::java::lang::Object {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::java::lang::String>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "java/lang/String", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("java/lang/String\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "java-lang-Throwable"))]
+        __jni_bindgen! {
+            /// public class java/lang/Throwable
+            ///
+            /// Required feature: "java-lang-Throwable"
+            public class Throwable ("java/lang/Throwable") extends crate::java::lang::Object {
+
+                /// <init>
+                pub fn new<'env>(__jni_env: &'env __jni_bindgen::Env) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::java::lang::Throwable>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "java/lang/Throwable", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("java/lang/Throwable\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Bindings generated by jni-bindgen's `emit_rust::fixture` test (see jni-bindgen/src/emit_rust/fixture.rs), and
+//! implementations of the traits they define.  Mostly this just needs to compile.
+
+#![allow(clippy::needless_lifetimes)] // Generated static accessors always name 'env, even where it could be elided.
+
+include!("bindings.rs");
+
+use com::example::{Listener, ListenerProxy, Parser, ParserError, RustLib, RustLibNatives};
+use java::lang::{Object, String as JString, Throwable};
+use jni_glue::{jni_sys::jclass, Env, Local, Ref};
+
+
+
+impl jni_glue::ThrowableType for java::lang::Throwable {}
+
+struct Natives;
+
+impl RustLibNatives for Natives {
+    fn add(_env: &Env, _class: jclass, arg0: i32, arg1: i32) -> i32 { arg0.wrapping_add(arg1) }
+    fn describe_object<'env>(env: &'env Env, _this: Ref<'env, RustLib>, arg0: Option<Ref<'env, Object>>) -> Option<Local<'env, JString>> { describe(env, if arg0.is_some() { "some" } else { "null" }) }
+    fn describe_int<'env>(env: &'env Env, _this: Ref<'env, RustLib>, arg0: i32) -> Option<Local<'env, JString>> { describe(env, &arg0.to_string()) }
+    fn get_count<'env>(_env: &'env Env, _this: Ref<'env, RustLib>) -> i32 { 42 }
+}
+
+com_example_RustLib_natives!(Natives);
+
+struct Doubler;
+
+impl ListenerProxy for Doubler {
+    fn on_value_int(&self, _env: &Env, arg0: i32) -> i32 { arg0.wrapping_mul(2) }
+    fn on_value_long(&self, _env: &Env, arg0: i64) -> i64 { arg0.wrapping_mul(2) }
+    fn describe<'env>(&self, env: &'env Env, arg0: Option<Ref<'env, Object>>) -> Option<Local<'env, JString>> { describe(env, if arg0.is_some() { "some" } else { "null" }) }
+    fn fire(&self, _env: &Env) {}
+}
+
+/// Implements com.example.Listener in Rust.
+pub fn new_listener(env: &Env) -> Result<Local<'_, Listener>, Local<'_, Throwable>> {
+    Listener::new_proxy(env, Doubler)
+}
+
+/// Parses `text`, rendering anything but a com.example.ParseException with the exception enum's Debug impl.
+pub fn parse(parser: &Parser, text: &JString) -> Result<i32, String> {
+    parser.parse(text).map_err(|err| match err {
+        ParserError::ParseException(_)  => "malformed".to_owned(),
+        other                           => format!("{:?}", other),
+    })
+}
+
+/// Sorts an arbitrary exception into com.example.Parser's declared exceptions.
+pub fn parser_error(throwable: Local<'_, Throwable>) -> ParserError<'_> {
+    throwable.into()
+}
+
+fn describe<'env>(env: &'env Env, text: &str) -> Option<Local<'env, JString>> {
+    Some(unsafe { Local::from_env_object(env.as_jni_env(), env.new_string_from_str(text)) })
+}
+
+#[test] fn natives_exported() {
+    // Overloads get their argument descriptors appended, and `_`s are escaped.
+    let _ : &[*const ()] = &[
+        Java_com_example_RustLib_add                            as *const (),
+        Java_com_example_RustLib_describe__Ljava_lang_Object_2  as *const (),
+        Java_com_example_RustLib_describe__I                    as *const (),
+        Java_com_example_RustLib_get_1count                     as *const (),
+    ];
+}
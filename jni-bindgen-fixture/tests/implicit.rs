@@ -0,0 +1,33 @@
+//! Bindings generated by jni-bindgen's `emit_rust::fixture` test with `static_env = "implicit"` and
+//! `string_params = "into_java_string"` (see jni-bindgen/src/emit_rust/fixture.rs).  There's no JVM with these classes
+//! to run them against, so this just needs to compile.
+
+include!("implicit/bindings.rs");
+
+use com::example::Settings;
+use java::lang::{String as JString, Throwable};
+use jni_glue::{Env, Local};
+
+
+
+impl jni_glue::ThrowableType for java::lang::Throwable {}
+
+#[test] fn implicit_statics() {
+    // Statics and constructors don't take an Env, and accept &str for java.lang.String parameters.
+    let _ = || -> Result<Option<Local<'static, JString>>, Local<'static, Throwable>> { unsafe {
+        let _settings = Settings::new("settings")?;
+        Settings::set_name(&String::from("name"))?;
+        Settings::set_count(Settings::count() + 1);
+        Settings::set_label(Settings::label().as_deref());
+        Settings::get_name()
+    }};
+}
+
+#[test] fn explicit_instance_methods() {
+    // Instance methods still use their object's Env, but accept &str for java.lang.String parameters too.
+    let _ = |env: &Env, settings: &Settings| -> Result<i32, Local<'static, Throwable>> {
+        let _ = env;
+        let _ = settings.describe("text", 42);
+        Ok(0)
+    };
+}
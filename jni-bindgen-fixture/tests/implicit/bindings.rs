@@ -0,0 +1,465 @@
+// WARNING:  This file was autogenerated by jni-bindgen.  Any changes to this file may be lost!!!
+
+#[allow(unused_imports)] #[macro_use(__jni_bindgen)] extern crate jni_glue;
+#[doc(hidden)] use jni_glue as __jni_bindgen;
+
+
+
+#[allow(non_camel_case_types)]   // We map Java inner classes to Outer_Inner
+#[allow(dead_code)]              // We generate structs for private Java types too, just in case.
+#[allow(deprecated)]             // We're generating deprecated types/methods
+#[allow(non_upper_case_globals)] // We might be generating Java style fields/methods
+#[allow(non_snake_case)]         // We might be generating Java style fields/methods
+pub mod com {
+    #[allow(unused_imports)] use super::__jni_bindgen;
+
+    pub mod example {
+        #[allow(unused_imports)] use super::__jni_bindgen;
+
+        #[cfg(any(feature = "all", feature = "com-example-FormatException"))]
+        __jni_bindgen! {
+            /// public class com/example/FormatException
+            ///
+            /// Required feature: "com-example-FormatException"
+            public class FormatException ("com/example/FormatException") extends crate::java::lang::Throwable {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::FormatException>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/FormatException", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/FormatException\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Listener"))]
+        __jni_bindgen! {
+            /// public interface com/example/Listener
+            ///
+            /// Required feature: "com-example-Listener"
+            public interface Listener ("com/example/Listener") extends crate::java::lang::Object {
+
+                /// onValue
+                pub fn on_value_int<'env>(&'env self, arg0: i32) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "onValue", .descriptor == "(I)I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "onValue\0", "(I)I\0");
+                        __jni_env.call_int_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// onValue
+                pub fn on_value_long<'env>(&'env self, arg0: i64) -> __jni_bindgen::std::result::Result<i64, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "onValue", .descriptor == "(J)J"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "onValue\0", "(J)J\0");
+                        __jni_env.call_long_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-Object", "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-Object", feature = "java-lang-String")))]
+                pub fn describe<'env>(&'env self, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::Object>>) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "describe", .descriptor == "(Ljava/lang/Object;)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "describe\0", "(Ljava/lang/Object;)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// fire
+                pub fn fire<'env>(&'env self) -> __jni_bindgen::std::result::Result<(), __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Listener", java.flags == PUBLIC | ABSTRACT, .name == "fire", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Listener\0", "fire\0", "()V\0");
+                        __jni_env.call_void_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-ParseException"))]
+        __jni_bindgen! {
+            /// public class com/example/ParseException
+            ///
+            /// Required feature: "com-example-ParseException"
+            public class ParseException ("com/example/ParseException") extends crate::java::lang::Throwable {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::ParseException>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/ParseException", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/ParseException\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Parser"))]
+        __jni_bindgen! {
+            /// public class com/example/Parser
+            ///
+            /// Required feature: "com-example-Parser"
+            public class Parser ("com/example/Parser") extends crate::java::lang::Object {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::Parser>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Parser", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Parser\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// parse
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn parse<'env>(&'env self, arg0: impl __jni_bindgen::IntoJavaString<'env, crate::java::lang::String>) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Parser", java.flags == PUBLIC, .name == "parse", .descriptor == "(Ljava/lang/String;)I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let arg0 = __jni_bindgen::IntoJavaString::into_java_string(arg0, __jni_env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Parser\0", "parse\0", "(Ljava/lang/String;)I\0");
+                        __jni_env.call_int_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// format
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn format<'env>(&'env self, arg0: i32) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Parser", java.flags == PUBLIC, .name == "format", .descriptor == "(I)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Parser\0", "format\0", "(I)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-RustLib"))]
+        __jni_bindgen! {
+            /// public class com/example/RustLib
+            ///
+            /// Required feature: "com-example-RustLib"
+            public class RustLib ("com/example/RustLib") extends crate::java::lang::Object {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::RustLib>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// add
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn add<'env>(arg0: i32, arg1: i32) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | STATIC | NATIVE, .name == "add", .descriptor == "(II)I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0), __jni_bindgen::AsJValue::as_jvalue(&arg1)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_static_method("com/example/RustLib\0", "add\0", "(II)I\0");
+                        __jni_env.call_static_int_method_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-Object", "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-Object", feature = "java-lang-String")))]
+                pub fn describe_object<'env>(&'env self, arg0: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env crate::java::lang::Object>>) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | NATIVE, .name == "describe", .descriptor == "(Ljava/lang/Object;)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0.into())];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "describe\0", "(Ljava/lang/Object;)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn describe_int<'env>(&'env self, arg0: i32) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | NATIVE, .name == "describe", .descriptor == "(I)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "describe\0", "(I)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// get_count
+                pub fn get_count<'env>(&'env self) -> __jni_bindgen::std::result::Result<i32, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/RustLib", java.flags == PUBLIC | NATIVE, .name == "get_count", .descriptor == "()I"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/RustLib\0", "get_count\0", "()I\0");
+                        __jni_env.call_int_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "com-example-Settings"))]
+        __jni_bindgen! {
+            /// public class com/example/Settings
+            ///
+            /// Required feature: "com-example-Settings"
+            public class Settings ("com/example/Settings") extends crate::java::lang::Object {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub unsafe fn new<'env>(arg0: impl __jni_bindgen::IntoJavaString<'env, crate::java::lang::String>) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::com::example::Settings>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC, .name == "<init>", .descriptor == "(Ljava/lang/String;)V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let arg0 = __jni_bindgen::IntoJavaString::into_java_string(arg0, __jni_env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Settings\0", "<init>\0", "(Ljava/lang/String;)V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// getName
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub unsafe fn get_name<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC | STATIC, .name == "getName", .descriptor == "()Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_static_method("com/example/Settings\0", "getName\0", "()Ljava/lang/String;\0");
+                        __jni_env.call_static_object_method_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// setName
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub unsafe fn set_name<'env>(arg0: impl __jni_bindgen::IntoJavaString<'env, crate::java::lang::String>) -> __jni_bindgen::std::result::Result<(), __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC | STATIC, .name == "setName", .descriptor == "(Ljava/lang/String;)V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let arg0 = __jni_bindgen::IntoJavaString::into_java_string(arg0, __jni_env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_static_method("com/example/Settings\0", "setName\0", "(Ljava/lang/String;)V\0");
+                        __jni_env.call_static_void_method_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// describe
+                ///
+                /// Required features: "java-lang-String"
+                #[cfg(any(feature = "all", all(feature = "java-lang-String")))]
+                pub fn describe<'env>(&'env self, arg0: impl __jni_bindgen::IntoJavaString<'env, crate::java::lang::String>, arg1: i32) -> __jni_bindgen::std::result::Result<__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "com/example/Settings", java.flags == PUBLIC, .name == "describe", .descriptor == "(Ljava/lang/String;I)Ljava/lang/String;"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);
+                        let arg0 = __jni_bindgen::IntoJavaString::into_java_string(arg0, __jni_env);
+                        let __jni_args = [__jni_bindgen::AsJValue::as_jvalue(&arg0), __jni_bindgen::AsJValue::as_jvalue(&arg1)];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("com/example/Settings\0", "describe\0", "(Ljava/lang/String;I)Ljava/lang/String;\0");
+                        __jni_env.call_object_method_a(self.0.object, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+
+                /// **get** public static count
+                pub fn count() -> i32 {
+                    unsafe {
+                        let env = __jni_bindgen::Env::from_implicit_vm();
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "count\0", "I\0");
+                        env.get_static_int_field(__jni_class, __jni_field)
+                    }
+                }
+
+                /// **set** public static count
+                pub fn set_count(value: i32) {
+                    unsafe {
+                        let env = __jni_bindgen::Env::from_implicit_vm();
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "count\0", "I\0");
+                        env.set_static_int_field(__jni_class, __jni_field, value)
+                    }
+                }
+
+                /// **get** public static label
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned reference must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                ///
+                /// Required feature: "java-lang-String"
+                #[cfg(any(feature = "all", feature = "java-lang-String"))]
+                pub unsafe fn label<'env>() -> __jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, crate::java::lang::String>> {
+                    unsafe {
+                        let env = __jni_bindgen::Env::from_implicit_vm();
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "label\0", "Ljava/lang/String;\0");
+                        env.get_static_object_field(__jni_class, __jni_field)
+                    }
+                }
+
+                /// **set** public static label
+                ///
+                /// Required feature: "java-lang-String"
+                #[cfg(any(feature = "all", feature = "java-lang-String"))]
+                pub fn set_label<'obj>(value: impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'obj crate::java::lang::String>>) {
+                    unsafe {
+                        let env = __jni_bindgen::Env::from_implicit_vm();
+                        let (__jni_class, __jni_field) = env.require_class_static_field("com/example/Settings\0", "label\0", "Ljava/lang/String;\0");
+                        env.set_static_object_field(__jni_class, __jni_field, value)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]   // We map Java inner classes to Outer_Inner
+#[allow(dead_code)]              // We generate structs for private Java types too, just in case.
+#[allow(deprecated)]             // We're generating deprecated types/methods
+#[allow(non_upper_case_globals)] // We might be generating Java style fields/methods
+#[allow(non_snake_case)]         // We might be generating Java style fields/methods
+pub mod java {
+    #[allow(unused_imports)] use super::__jni_bindgen;
+
+    pub mod lang {
+        #[allow(unused_imports)] use super::__jni_bindgen;
+
+        #[cfg(any(feature = "all", feature = "java-lang-Object"))]
+        __jni_bindgen! {
+            /// public class java/lang/Object
+            ///
+            /// Required feature: "java-lang-Object"
+            public class Object ("java/lang/Object") extends () {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::java::lang::Object>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "java/lang/Object", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("java/lang/Object\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "java-lang-String"))]
+        __jni_bindgen! {
+            /// public final class java/lang/String
+            ///
+            /// Required feature: "java-lang-String"
+            public final class String ("java/lang/String") extends crate::java::lang::Object {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::java::lang::String>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "java/lang/String", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("java/lang/String\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(feature = "all", feature = "java-lang-Throwable"))]
+        __jni_bindgen! {
+            /// public class java/lang/Throwable
+            ///
+            /// Required feature: "java-lang-Throwable"
+            public class Throwable ("java/lang/Throwable") extends crate::java::lang::Object {
+
+                /// <init>
+                ///
+                /// # Safety
+                ///
+                /// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)
+                pub unsafe fn new<'env>() -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, crate::java::lang::Throwable>, __jni_bindgen::Local<'env, crate::java::lang::Throwable>> {
+                    // class.path == "java/lang/Throwable", java.flags == PUBLIC, .name == "<init>", .descriptor == "()V"
+                    unsafe {
+                        let __jni_env = __jni_bindgen::Env::from_implicit_vm();
+                        let __jni_args = [];
+                        let (__jni_class, __jni_method) = __jni_env.require_class_method("java/lang/Throwable\0", "<init>\0", "()V\0");
+                        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())
+                    }
+                }
+            }
+        }
+    }
+}
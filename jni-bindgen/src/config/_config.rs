@@ -2,5 +2,6 @@
 
 #[allow(unused_imports)] use super::*;
 
+pub mod glue;
 pub mod runtime;
 pub mod toml;
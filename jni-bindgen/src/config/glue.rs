@@ -0,0 +1,153 @@
+//! Glue `.toml` file structures and parsing APIs, describing Java classes implemented in Rust.
+
+use jreflection::{class, method};
+use serde_derive::*;
+
+use std::fs;
+use std::io;
+use std::path::*;
+
+
+
+/// The \[class\] section.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Class {
+    /// The JNI path of the class, e.g. "com/example/RustLib".
+    pub path: String,
+
+    /// The JNI path of the superclass, which must have an accessible no-argument constructor.
+    #[serde(default = "default_extends")]
+    pub extends: String,
+
+    /// If set, the class will `System.loadLibrary(...)` this library when initialized.
+    pub load_library: Option<String>,
+}
+
+fn default_extends() -> String { "java/lang/Object".to_owned() }
+
+/// The \[output\] section.  Paths are relative to the glue file.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Output {
+    /// Target `.java` file to generate, if any.
+    pub java: Option<PathBuf>,
+
+    /// Target `.class` file to generate, if any.
+    pub class: Option<PathBuf>,
+}
+
+/// A \[[native\]] section.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Native {
+    pub name:       String,
+    pub signature:  String,
+    #[serde(rename = "static")] #[serde(default = "Default::default")]
+    pub is_static:  bool,
+}
+
+/// Format for a glue `.toml` file, describing a Java class with `native` methods to be implemented in Rust.
+///
+/// Glue files may be listed in jni-bindgen.toml's `[input] files` alongside `.jar`s and `.class`es.  jni-bindgen emits
+/// the Java side of the class as `.java` source and/or a `.class` file, and generates the Rust side as if the class had
+/// been read from a `.jar` - including, with `native_traits = true`, the `{Class}Natives` trait and export macro.
+///
+/// # Example File
+///
+/// ```toml
+/// [class]
+/// path            = "com/example/RustLib"
+/// extends         = "java/lang/Object"
+/// load_library    = "rustlib"
+///
+/// [output]
+/// java            = "java/com/example/RustLib.java"
+/// class           = "classes/com/example/RustLib.class"
+///
+/// [[native]]
+/// name            = "add"
+/// signature       = "(II)I"
+/// static          = true
+///
+/// [[native]]
+/// name            = "describe"
+/// signature       = "(Ljava/lang/Object;)Ljava/lang/String;"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct File {
+    pub class: Class,
+
+    #[serde(default = "Default::default")]
+    pub output: Output,
+
+    #[serde(rename = "native")] #[serde(default = "Vec::new")]
+    pub natives: Vec<Native>,
+}
+
+impl File {
+    /// Read from a file, under the assumption that it's in the glue `.toml` file format.
+    pub fn read_path(path: &Path) -> io::Result<Self> {
+        Self::read_str(&fs::read_to_string(path)?[..])
+    }
+
+    /// Read from a memory buffer, under the assumption that it's in the glue `.toml` file format.
+    pub fn read_str(buffer: &str) -> io::Result<Self> {
+        let file : File = toml::from_str(buffer).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(file)
+    }
+
+    /// The described class:  a public class with a public no-argument constructor, and the described native methods.
+    pub fn to_class(&self) -> io::Result<jreflection::Class> {
+        let mut methods = vec![jreflection::Method::new(method::Flags::PUBLIC, "<init>".to_owned(), "()V".to_owned())?];
+        for native in self.natives.iter() {
+            let mut flags = method::Flags::PUBLIC | method::Flags::NATIVE;
+            if native.is_static { flags |= method::Flags::STATIC; }
+            methods.push(jreflection::Method::new(flags, native.name.clone(), native.signature.clone())?);
+        }
+
+        Ok(jreflection::Class {
+            flags:      class::Flags::PUBLIC | class::Flags::SUPER,
+            path:       class::IdBuf::new(self.class.path.clone()),
+            super_path: Some(class::IdBuf::new(self.class.extends.clone())),
+            methods,
+            .. Default::default()
+        })
+    }
+}
+
+#[test] fn load_glue_toml() {
+    let file = File::read_str(r#"
+        [class]
+        path            = "com/example/RustLib"
+        load_library    = "rustlib"
+
+        [output]
+        java            = "java/com/example/RustLib.java"
+
+        [[native]]
+        name            = "add"
+        signature       = "(II)I"
+        static          = true
+
+        [[native]]
+        name            = "describe"
+        signature       = "(Ljava/lang/Object;)Ljava/lang/String;"
+    "#).unwrap();
+
+    assert_eq!(file.class.path,             "com/example/RustLib");
+    assert_eq!(file.class.extends,          "java/lang/Object");
+    assert_eq!(file.class.load_library,     Some("rustlib".to_owned()));
+    assert_eq!(file.output.java,            Some(PathBuf::from("java/com/example/RustLib.java")));
+    assert_eq!(file.output.class,           None);
+    assert_eq!(file.natives.len(),          2);
+    assert_eq!(file.natives[0].is_static,   true);
+    assert_eq!(file.natives[1].is_static,   false);
+
+    let class = file.to_class().unwrap();
+    assert_eq!(class.path.as_str(), "com/example/RustLib");
+    assert_eq!(class.methods.iter().map(|m| (m.name.as_str(), m.descriptor_str(), m.is_native(), m.is_static())).collect::<Vec<_>>(), vec![
+        ("<init>",      "()V",                                      false,  false),
+        ("add",         "(II)I",                                    true,   true),
+        ("describe",    "(Ljava/lang/Object;)Ljava/lang/String;",   true,   false),
+    ]);
+
+    assert!(File::read_str("[class]\npath = \"a/B\"\n[[native]]\nname = \"f\"\nsignature = \"(\"").unwrap().to_class().is_err());
+}
@@ -12,16 +12,24 @@ use std::path::*;
 
 
 /// How should the JNIEnv be passed around.
-///
-/// In the future, I plan to support an "Implicit" mode for platforms like Android - where there's at most one JVM at a
-/// time - and said JVM lives for the lifetime of the application - so we can save some boilerplate for end users by
-/// hiding it's use as an implementation detail.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum StaticEnvStyle {
     /// All static methods, static field getters, global hydration, etc. requires an explicit env parameter.
     Explicit,
 
+    /// Static methods, constructors, and static field accessors get the current thread's env from the one and only
+    /// loaded JVM, attaching the current thread if necessary.  Intended for platforms like Android - where there's at
+    /// most one JVM at a time - and said JVM lives for the lifetime of the application - so we can save some
+    /// boilerplate for end users by hiding it's use as an implementation detail.
+    ///
+    /// Trade-off:  without an env parameter, nothing bounds the `'env` lifetime of returned references - so static
+    /// methods, constructors, and static object field getters are generated as `unsafe fn`s, and calling them still
+    /// needs an `unsafe` block.  Callers must not let returned references outlive the current thread's local frame.
+    /// Primitive getters and setters remain safe.  If you'd rather have safe calls, stick with `Explicit` and get an env
+    /// where you don't have one from `Env::with_implicit_vm(|env| ...)`.
+    Implicit,
+
     #[doc(hidden)] __NonExhaustive,
 }
 
@@ -29,6 +37,24 @@ impl Default for StaticEnvStyle {
     fn default() -> Self { StaticEnvStyle::Explicit }
 }
 
+/// How should java.lang.String parameters be accepted.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StringParamStyle {
+    /// Accept `impl Into<Option<&java::lang::String>>`, like any other object parameter.
+    IntoOption,
+
+    /// Accept `impl IntoJavaString<java::lang::String>`, which also allows passing `&str`, `&String`, etc. directly.
+    /// Temporary java strings are created and deleted by the generated method.
+    IntoJavaString,
+
+    #[doc(hidden)] __NonExhaustive,
+}
+
+impl Default for StringParamStyle {
+    fn default() -> Self { StringParamStyle::IntoOption }
+}
+
 /// How should the generated Rust code be split up.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -46,6 +72,26 @@ impl Default for CodeShardingStyle {
     fn default() -> Self { CodeShardingStyle::PerClass }
 }
 
+/// Should methods with a `throws` clause return a typed enum of the exceptions they're declared to throw?
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExceptionEnumStyle {
+    /// All methods return `Local<throwable_type>` errors, regardless of their `throws` clause.
+    None,
+
+    /// Each method with a `throws` clause gets its own `{Class}{Method}Error` enum.
+    PerMethod,
+
+    /// Each class gets a single `{Class}Error` enum, covering everything any of its methods are declared to throw.
+    PerClass,
+
+    #[doc(hidden)] __NonExhaustive,
+}
+
+impl Default for ExceptionEnumStyle {
+    fn default() -> Self { ExceptionEnumStyle::None }
+}
+
 fn default_throwable_type() -> String { "jni_android_sys::java::lang::Throwable".to_owned() }
 fn default_true() -> bool { true }
 fn default_method_naming_style() -> MethodManglingStyle { MethodManglingStyle::Rustify }
@@ -58,6 +104,10 @@ pub struct CodeGen {
     #[serde(default = "Default::default")]
     pub static_env: StaticEnvStyle,
 
+    /// How java.lang.String parameters should be accepted.
+    #[serde(default = "Default::default")]
+    pub string_params: StringParamStyle,
+
     /// How methods should be named by default.
     #[serde(default = "default_method_naming_style")]
     pub method_naming_style: MethodManglingStyle,
@@ -85,12 +135,28 @@ pub struct CodeGen {
     /// Should not-emitted methods/fields still generate their code commented out?
     #[serde(default = "default_true")]
     pub keep_rejected_emits: bool,
+
+    /// Should classes with `native` methods get a `{Class}Natives` trait, and a `{jni_mangled_class}_natives!` macro
+    /// exporting the `extern "system" fn Java_...` symbols that forward to your implementation of said trait?
+    #[serde(default = "Default::default")]
+    pub native_traits: bool,
+
+    /// Should interfaces get a `{Interface}Proxy` trait, and a `{Interface}::new_proxy` constructor implementing said
+    /// interface by forwarding calls to your implementation of said trait?
+    #[serde(default = "Default::default")]
+    pub proxy_traits: bool,
+
+    /// Should methods with a `throws` clause return an enum with a variant per declared exception (plus `Other`),
+    /// instead of a plain `Local<throwable_type>`?
+    #[serde(default = "Default::default")]
+    pub exception_enums: ExceptionEnumStyle,
 }
 
 impl Default for CodeGen {
     fn default() -> Self {
         Self {
             static_env:                     Default::default(),
+            string_params:                  Default::default(),
             method_naming_style:            default_method_naming_style(),
             method_naming_style_collision:  default_method_naming_style_collision(),
             field_naming_style:             Default::default(),
@@ -98,6 +164,9 @@ impl Default for CodeGen {
             shard_structs:                  true,
             feature_per_struct:             true,
             keep_rejected_emits:            true,
+            native_traits:                  false,
+            proxy_traits:                   false,
+            exception_enums:                Default::default(),
         }
     }
 }
@@ -199,7 +268,8 @@ pub struct Documentation {
 /// The \[input\] section.
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct Input {
-    /// `.jar` or `.class` files to scan for JVM class info.
+    /// `.jar` or `.class` files to scan for JVM class info, or glue `.toml` files describing Java classes to implement
+    /// in Rust (see [config::glue::File](../glue/struct.File.html).)
     ///
     /// May in the future add support for `.apk`s, `.aab`s, etc.
     pub files: Vec<PathBuf>,
@@ -250,8 +320,12 @@ pub struct Rename {
 ///
 /// [codegen]
 /// static_env                      = "implicit"
+/// string_params                   = "into_java_string"
 /// method_naming_style             = "java"
 /// method_naming_style_collision   = "rustify_long_signature"
+/// native_traits                   = true
+/// proxy_traits                    = true
+/// exception_enums                 = "per_method"
 ///
 /// [logging]
 /// verbose = true
@@ -388,6 +462,9 @@ impl File {
         static_env                      = "explicit"
         method_naming_style             = "java"
         method_naming_style_collision   = "rustify_long_signature"
+        native_traits                   = true
+        proxy_traits                    = true
+        exception_enums                 = "per_class"
 
         [logging]
         verbose = true
@@ -448,6 +525,9 @@ impl File {
     assert_eq!(file.codegen.static_env,                     StaticEnvStyle::Explicit);
     assert_eq!(file.codegen.method_naming_style,            MethodManglingStyle::Java);
     assert_eq!(file.codegen.method_naming_style_collision,  MethodManglingStyle::RustifyLongSignature);
+    assert_eq!(file.codegen.native_traits,                  true);
+    assert_eq!(file.codegen.proxy_traits,                   true);
+    assert_eq!(file.codegen.exception_enums,                ExceptionEnumStyle::PerClass);
 
     assert_eq!(file.logging.verbose, true);
 
@@ -504,6 +584,24 @@ impl File {
     assert_eq!(file.renames[2].to,         "some_other_method");
 }
 
+#[test] fn load_implicit_codegen_toml() {
+    let implicit_toml = r#"
+        [codegen]
+        static_env      = "implicit"
+        string_params   = "into_java_string"
+
+        [input]
+        files = ["%LOCALAPPDATA%/Android/Sdk/platforms/android-28/android.jar"]
+
+        [output]
+        path = "android28.rs"
+    "#;
+    let file = File::read_str(implicit_toml).unwrap();
+
+    assert_eq!(file.codegen.static_env,     StaticEnvStyle::Implicit);
+    assert_eq!(file.codegen.string_params,  StringParamStyle::IntoJavaString);
+}
+
 #[test] fn load_minimal_toml() {
     let minimal_toml = r#"
         [input]
@@ -515,8 +613,12 @@ impl File {
     let file = File::read_str(minimal_toml).unwrap();
 
     assert_eq!(file.codegen.static_env,                     StaticEnvStyle::Explicit);
+    assert_eq!(file.codegen.string_params,                  StringParamStyle::IntoOption);
     assert_eq!(file.codegen.method_naming_style,            MethodManglingStyle::Rustify);
     assert_eq!(file.codegen.method_naming_style_collision,  MethodManglingStyle::RustifyShortSignature);
+    assert_eq!(file.codegen.native_traits,                  false);
+    assert_eq!(file.codegen.proxy_traits,                   false);
+    assert_eq!(file.codegen.exception_enums,                ExceptionEnumStyle::None);
 
     assert_eq!(file.logging.verbose, false);
     assert_eq!(file.documentation.patterns.len(), 0);
@@ -0,0 +1,9 @@
+//! Java generation logic, for the Java side of classes implemented in Rust
+
+#[allow(unused_imports)] use super::*;
+
+mod class_file;
+mod java_source;
+
+pub use class_file::*;
+pub use java_source::*;
@@ -0,0 +1,191 @@
+use crate::emit_java::*;
+
+use jni_glue::encode_modified_utf8;
+
+use std::io::{self, Write};
+
+
+
+const MAJOR_VERSION     : u16 = 52; // Java 8:  No StackMapTable required for our branchless code, and d8 friendly.
+
+const ACC_PUBLIC        : u16 = 0x0001;
+const ACC_STATIC        : u16 = 0x0008;
+const ACC_SUPER         : u16 = 0x0020;
+const ACC_NATIVE        : u16 = 0x0100;
+
+const ALOAD_0           : u8 = 0x2a;
+const LDC_W             : u8 = 0x13;
+const INVOKESPECIAL     : u8 = 0xb7;
+const INVOKESTATIC      : u8 = 0xb8;
+const RETURN            : u8 = 0xb1;
+
+/// Writes a `.class` file for `class`, equivalent to compiling the source from [write_java_source].  `class` is
+/// expected to come from a glue file, with only a public no-argument constructor and native methods.
+///
+/// [write_java_source]:    fn.write_java_source.html
+pub fn write_class_file(class: &jreflection::Class, load_library: Option<&str>, out: &mut impl Write) -> io::Result<()> {
+    let path        = class.path.as_str();
+    let super_path  = class.super_path.as_ref().map_or("java/lang/Object", |p| p.as_str());
+
+    let mut constants = ConstantPool::default();
+    let this_class  = constants.class(path);
+    let super_class = constants.class(super_path);
+    let code        = constants.utf8("Code");
+
+    let mut methods = Vec::new();
+    for method in class.methods.iter() {
+        if method.is_constructor() {
+            let super_init = constants.method_ref(super_path, "<init>", "()V");
+            let mut bytecode = vec![ALOAD_0, INVOKESPECIAL];
+            bytecode.extend_from_slice(&super_init.to_be_bytes());
+            bytecode.push(RETURN);
+            methods.push(MethodInfo { flags: ACC_PUBLIC, name: constants.utf8("<init>"), descriptor: constants.utf8("()V"), code: Some((1, 1, bytecode)) });
+        } else if method.is_native() {
+            let flags = ACC_PUBLIC | ACC_NATIVE | if method.is_static() { ACC_STATIC } else { 0 };
+            methods.push(MethodInfo { flags, name: constants.utf8(&method.name), descriptor: constants.utf8(method.descriptor_str()), code: None });
+        } else {
+            return io_data_err!("Unable to write class file for {}.{}:  Only native methods and the constructor are supported", path, &method.name);
+        }
+    }
+
+    if let Some(library) = load_library {
+        let library         = constants.string(library);
+        let load_library    = constants.method_ref("java/lang/System", "loadLibrary", "(Ljava/lang/String;)V");
+        let mut bytecode = vec![LDC_W];
+        bytecode.extend_from_slice(&library.to_be_bytes());
+        bytecode.push(INVOKESTATIC);
+        bytecode.extend_from_slice(&load_library.to_be_bytes());
+        bytecode.push(RETURN);
+        methods.push(MethodInfo { flags: ACC_STATIC, name: constants.utf8("<clinit>"), descriptor: constants.utf8("()V"), code: Some((1, 0, bytecode)) });
+    }
+
+    out.write_all(&0xCAFEBABEu32.to_be_bytes())?;
+    out.write_all(&0u16.to_be_bytes())?;
+    out.write_all(&MAJOR_VERSION.to_be_bytes())?;
+    constants.write(out)?;
+    out.write_all(&(ACC_PUBLIC | ACC_SUPER).to_be_bytes())?;
+    out.write_all(&this_class.to_be_bytes())?;
+    out.write_all(&super_class.to_be_bytes())?;
+    out.write_all(&0u16.to_be_bytes())?; // interfaces
+    out.write_all(&0u16.to_be_bytes())?; // fields
+    out.write_all(&(methods.len() as u16).to_be_bytes())?;
+    for method in methods.iter() {
+        method.write(code, out)?;
+    }
+    out.write_all(&0u16.to_be_bytes())?; // attributes
+    Ok(())
+}
+
+struct MethodInfo {
+    flags:      u16,
+    name:       u16,
+    descriptor: u16,
+    code:       Option<(u16, u16, Vec<u8>)>, // max_stack, max_locals, bytecode
+}
+
+impl MethodInfo {
+    fn write(&self, code_attribute_name: u16, out: &mut impl Write) -> io::Result<()> {
+        out.write_all(&self.flags.to_be_bytes())?;
+        out.write_all(&self.name.to_be_bytes())?;
+        out.write_all(&self.descriptor.to_be_bytes())?;
+        match self.code.as_ref() {
+            None => out.write_all(&0u16.to_be_bytes())?,
+            Some((max_stack, max_locals, bytecode)) => {
+                out.write_all(&1u16.to_be_bytes())?;
+                out.write_all(&code_attribute_name.to_be_bytes())?;
+                out.write_all(&(12 + bytecode.len() as u32).to_be_bytes())?;
+                out.write_all(&max_stack.to_be_bytes())?;
+                out.write_all(&max_locals.to_be_bytes())?;
+                out.write_all(&(bytecode.len() as u32).to_be_bytes())?;
+                out.write_all(&bytecode[..])?;
+                out.write_all(&0u16.to_be_bytes())?; // exception table
+                out.write_all(&0u16.to_be_bytes())?; // attributes
+            },
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Constant {
+    Utf8(String),
+    Class(u16),
+    String(u16),
+    NameAndType(u16, u16),
+    MethodRef(u16, u16),
+}
+
+#[derive(Default)]
+struct ConstantPool(Vec<Constant>);
+
+impl ConstantPool {
+    fn add(&mut self, constant: Constant) -> u16 {
+        let index = match self.0.iter().position(|c| *c == constant) {
+            Some(index) => index,
+            None        => { self.0.push(constant); self.0.len() - 1 },
+        };
+        index as u16 + 1 // Constant pool indicies are 1-based
+    }
+
+    fn utf8       (&mut self, value: &str) -> u16 { self.add(Constant::Utf8(value.to_owned())) }
+    fn class      (&mut self, path: &str) -> u16 { let name = self.utf8(path); self.add(Constant::Class(name)) }
+    fn string     (&mut self, value: &str) -> u16 { let value = self.utf8(value); self.add(Constant::String(value)) }
+
+    fn method_ref(&mut self, class: &str, name: &str, descriptor: &str) -> u16 {
+        let class       = self.class(class);
+        let name        = self.utf8(name);
+        let descriptor  = self.utf8(descriptor);
+        let name_and_type = self.add(Constant::NameAndType(name, descriptor));
+        self.add(Constant::MethodRef(class, name_and_type))
+    }
+
+    fn write(&self, out: &mut impl Write) -> io::Result<()> {
+        out.write_all(&(self.0.len() as u16 + 1).to_be_bytes())?;
+        for constant in self.0.iter() {
+            match constant {
+                Constant::Utf8(value) => {
+                    let value = encode_modified_utf8(value);
+                    out.write_all(&[1])?;
+                    out.write_all(&(value.len() as u16).to_be_bytes())?;
+                    out.write_all(&value[..])?;
+                },
+                Constant::Class(name)                       => { out.write_all(&[7])?;  out.write_all(&name.to_be_bytes())?; },
+                Constant::String(value)                     => { out.write_all(&[8])?;  out.write_all(&value.to_be_bytes())?; },
+                Constant::NameAndType(name, descriptor)     => { out.write_all(&[12])?; out.write_all(&name.to_be_bytes())?; out.write_all(&descriptor.to_be_bytes())?; },
+                Constant::MethodRef(class, name_and_type)   => { out.write_all(&[10])?; out.write_all(&class.to_be_bytes())?; out.write_all(&name_and_type.to_be_bytes())?; },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test] fn write_class_file_round_trip() {
+    let file = crate::config::glue::File::read_str(r#"
+        [class]
+        path            = "com/example/RustLib"
+        load_library    = "rustlib"
+
+        [[native]]
+        name            = "add"
+        signature       = "(II)I"
+        static          = true
+
+        [[native]]
+        name            = "describe"
+        signature       = "(Ljava/lang/Object;)Ljava/lang/String;"
+    "#).unwrap();
+
+    let mut out = Vec::new();
+    write_class_file(&file.to_class().unwrap(), Some("rustlib"), &mut out).unwrap();
+
+    let class = jreflection::Class::read(&mut io::Cursor::new(&out[..])).unwrap();
+    assert_eq!(class.path.as_str(), "com/example/RustLib");
+    assert_eq!(class.super_path.as_ref().map(|p| p.as_str()), Some("java/lang/Object"));
+    assert!(class.is_public());
+    assert_eq!(class.methods.iter().map(|m| (m.name.as_str(), m.descriptor_str(), m.is_native(), m.is_static())).collect::<Vec<_>>(), vec![
+        ("<init>",      "()V",                                      false,  false),
+        ("add",         "(II)I",                                    true,   true),
+        ("describe",    "(Ljava/lang/Object;)Ljava/lang/String;",   true,   false),
+        ("<clinit>",    "()V",                                      false,  true),
+    ]);
+}
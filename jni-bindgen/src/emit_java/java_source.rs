@@ -0,0 +1,118 @@
+use crate::emit_java::*;
+
+use jreflection::{class, method};
+
+use std::io::{self, Write};
+
+
+
+/// Writes Java source for `class`, declaring its native methods.  `class` is expected to come from a glue file, with
+/// only a public no-argument constructor (left implicit) and native methods.
+pub fn write_java_source(class: &jreflection::Class, load_library: Option<&str>, out: &mut impl Write) -> io::Result<()> {
+    let path = class.path.as_str();
+    if path.contains('$') { return io_data_err!("Unable to write Java source for {:?}:  Inner classes aren't supported", path); }
+    let (package, name) = match path.rfind('/') {
+        Some(slash) => (Some(&path[..slash]), &path[slash+1..]),
+        None        => (None, path),
+    };
+
+    writeln!(out, "// WARNING:  This file was autogenerated by jni-bindgen.  Any changes to this file may be lost!!!")?;
+    if let Some(package) = package {
+        writeln!(out, "package {};", package.replace('/', "."))?;
+    }
+    writeln!(out, "")?;
+
+    write!(out, "public class {}", name)?;
+    if let Some(super_path) = class.super_path.as_ref() {
+        if super_path.as_str() != "java/lang/Object" {
+            write!(out, " extends {}", java_class_name(super_path.as_id()))?;
+        }
+    }
+    writeln!(out, " {{")?;
+
+    if let Some(library) = load_library {
+        writeln!(out, "    static {{ System.loadLibrary({:?}); }}", library)?;
+        writeln!(out, "")?;
+    }
+
+    for method in class.methods.iter() {
+        if method.is_constructor() { continue; } // Implicit
+        if !method.is_native() { return io_data_err!("Unable to write Java source for {}.{}:  Only native methods are supported", path, &method.name); }
+
+        let descriptor = method.descriptor();
+        let mut params = String::new();
+        for (idx, arg) in descriptor.arguments().enumerate() {
+            if idx != 0 { params.push_str(", "); }
+            params.push_str(&format!("{} arg{}", java_type(arg), idx));
+        }
+
+        let keywords = if method.is_static() { "public static native" } else { "public native" };
+        writeln!(out, "    {} {} {}({});", keywords, java_type(descriptor.return_type()), &method.name, params)?;
+    }
+
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+fn java_class_name(class: class::Id) -> String {
+    class.as_str().replace(['/', '$'], ".")
+}
+
+fn java_type(ty: method::Type) -> String {
+    let (levels, inner) = match ty {
+        method::Type::Single(inner)             => (0, inner),
+        method::Type::Array { levels, inner }   => (levels, inner),
+    };
+
+    let mut java = match inner {
+        method::BasicType::Boolean      => "boolean".to_owned(),
+        method::BasicType::Byte         => "byte".to_owned(),
+        method::BasicType::Char         => "char".to_owned(),
+        method::BasicType::Short        => "short".to_owned(),
+        method::BasicType::Int          => "int".to_owned(),
+        method::BasicType::Long         => "long".to_owned(),
+        method::BasicType::Float        => "float".to_owned(),
+        method::BasicType::Double       => "double".to_owned(),
+        method::BasicType::Void         => "void".to_owned(),
+        method::BasicType::Class(class) => java_class_name(class),
+    };
+    for _ in 0..levels { java.push_str("[]"); }
+    java
+}
+
+#[test] fn write_java_source_test() {
+    let file = crate::config::glue::File::read_str(r#"
+        [class]
+        path            = "com/example/RustLib"
+        extends         = "android/app/Activity"
+        load_library    = "rustlib"
+
+        [[native]]
+        name            = "add"
+        signature       = "(II)I"
+        static          = true
+
+        [[native]]
+        name            = "describe"
+        signature       = "(Ljava/lang/Object;[[BLandroid/view/View$OnClickListener;)[Ljava/lang/String;"
+
+        [[native]]
+        name            = "run"
+        signature       = "()V"
+    "#).unwrap();
+
+    let mut out = Vec::new();
+    write_java_source(&file.to_class().unwrap(), file.class.load_library.as_deref(), &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "\
+        // WARNING:  This file was autogenerated by jni-bindgen.  Any changes to this file may be lost!!!\n\
+        package com.example;\n\
+        \n\
+        public class RustLib extends android.app.Activity {\n\
+        \x20   static { System.loadLibrary(\"rustlib\"); }\n\
+        \n\
+        \x20   public static native int add(int arg0, int arg1);\n\
+        \x20   public native java.lang.String[] describe(java.lang.Object arg0, byte[][] arg1, android.view.View.OnClickListener arg2);\n\
+        \x20   public native void run();\n\
+        }\n\
+    ");
+}
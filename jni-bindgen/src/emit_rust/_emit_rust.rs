@@ -3,17 +3,24 @@
 #[allow(unused_imports)] use super::*;
 
 mod context;
+mod exceptions;
 mod fields;
+#[cfg(test)] mod fixture;
 mod known_docs_url;
 mod methods;
 mod modules;
+mod natives;
 mod preamble;
+mod proxies;
 mod structs;
 
 pub use context::Context;
+use exceptions::*;
 use fields::*;
 use known_docs_url::*;
 use methods::*;
 use modules::*;
+use natives::*;
 use preamble::*;
+use proxies::*;
 use structs::*;
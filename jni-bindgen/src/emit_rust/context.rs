@@ -33,12 +33,12 @@ impl<'a> Context<'a> {
         Ok(format!("{}::{}", m, s))
     }
 
-    pub fn add_struct(&mut self, class: jreflection::Class) -> Result<(), Box<dyn Error>> {
+    pub fn add_struct(&mut self, class: jreflection::Class, throws: HashMap<String, Vec<String>>) -> Result<(), Box<dyn Error>> {
         if self.config.ignore_classes.contains(class.path.as_str()) {
             return Ok(())
         }
 
-        let s = Struct::new(self, class)?;
+        let s = Struct::new(self, class, throws)?;
         let scope = if let Some(s) = s.rust.local_scope() { s } else { /* !local_scope = not part of this module, skip! */ return Ok(()); };
 
         if self.config.codegen.feature_per_struct {
@@ -0,0 +1,141 @@
+use crate::emit_rust::*;
+
+use jreflection::class;
+
+use std::io;
+
+
+
+/// The exceptions `method` gets typed variants for:  its `throws` clause, minus throwable_type itself (which `Other`
+/// already covers.)
+fn typed_exceptions<'a>(context: &Context, method: &Method<'a>) -> Vec<&'a str> {
+    let throwable = context.config.codegen.throwable_type.as_str();
+    let mut exceptions = Vec::new();
+    for exception in method.throws.iter().map(|e| e.as_str()) {
+        if exceptions.contains(&exception) { continue; }
+        if context.java_to_rust_path(class::Id(exception)).ok().as_deref() == Some(throwable) { continue; }
+        exceptions.push(exception);
+    }
+    exceptions
+}
+
+/// The name of the error enum `method` returns instead of a plain `Local<throwable_type>`, if any.
+pub(crate) fn exception_enum_name(context: &Context, method: &Method) -> Option<String> {
+    if !method.java.is_public() || method.java.is_bridge() || method.java.is_static_init() { return None; }
+    if typed_exceptions(context, method).is_empty() { return None; }
+
+    let struct_name = Struct::name_for(context, method.class.path.as_id()).ok()?;
+    match context.config.codegen.exception_enums {
+        config::toml::ExceptionEnumStyle::None            => None,
+        config::toml::ExceptionEnumStyle::PerMethod       => Some(format!("{}{}Error", struct_name, camel_case(&method.configured_rust_name(context)?))),
+        config::toml::ExceptionEnumStyle::PerClass        => Some(format!("{}Error", struct_name)),
+        config::toml::ExceptionEnumStyle::__NonExhaustive => None,
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let mut buf = String::new();
+    for word in name.trim_start_matches("r#").split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            buf.extend(first.to_uppercase());
+            buf.extend(chars);
+        }
+    }
+    buf
+}
+
+/// Emits the error enums named by [exception_enum_name] for `methods`, with a variant per declared exception, an
+/// `Other` variant for everything else, a `From<Local<throwable_type>>` conversion via `instanceof` checks, and a `Debug`
+/// impl rendering the exception's `jni_glue::ThrowableDetails`:
+///
+/// ```ignore
+/// match stream.read()? { ... } // Err(InputStreamReadError::IOException(e)) or Err(InputStreamReadError::Other(e))
+/// ```
+///
+/// [exception_enum_name]:  fn.exception_enum_name.html
+pub(crate) fn write_exception_enums(context: &Context, structure: &Struct, methods: &[Method], indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+    let mut enums : Vec<(String, Vec<&Method>, Vec<&str>)> = Vec::new(); // name, methods, exceptions
+    for method in methods {
+        let name = if let Some(name) = exception_enum_name(context, method) { name } else { continue };
+        let index = match enums.iter().position(|e| e.0 == name) {
+            Some(index) => index,
+            None        => { enums.push((name, Vec::new(), Vec::new())); enums.len() - 1 },
+        };
+        let (_, enum_methods, enum_exceptions) = &mut enums[index];
+        enum_methods.push(method);
+        for exception in typed_exceptions(context, method) {
+            if !enum_exceptions.contains(&exception) { enum_exceptions.push(exception); }
+        }
+    }
+
+    let struct_name = &structure.rust.struct_name;
+    let throwable   = context.config.codegen.throwable_type.as_str();
+    let cfg         = Struct::feature_for(context, structure.java.path.as_id()).ok().map(|f| format!("#[cfg(any(feature = \"all\", feature = {:?}))]", f));
+
+    for (enum_name, enum_methods, enum_exceptions) in enums.iter() {
+        writeln!(out, "")?;
+        write!(out, "{}/// Exceptions declared by ", indent)?;
+        for (idx, method) in enum_methods.iter().enumerate() {
+            if idx != 0 { write!(out, ", ")?; }
+            let name = method.configured_rust_name(context).unwrap_or_default();
+            write!(out, "[{}::{}](struct.{}.html#method.{})", struct_name, &name, struct_name, name.trim_start_matches("r#"))?;
+        }
+        writeln!(out, ".")?;
+        writeln!(out, "{}///", indent)?;
+        writeln!(out, "{}/// Converted from a thrown `{}` by checking each declared exception with `instanceof`, in `throws` order.", indent, throwable)?;
+        if let Some(cfg) = cfg.as_ref() { writeln!(out, "{}{}", indent, cfg)?; }
+        writeln!(out, "{}pub enum {}<'env> {{", indent, enum_name)?;
+        let mut variants = Vec::new();
+        for exception in enum_exceptions.iter() {
+            let id = class::Id(exception);
+            let (variant, path) = match (Struct::name_for(context, id), context.java_to_rust_path(id)) {
+                (Ok(variant), Ok(path)) => (variant, path),
+                _ => { writeln!(out, "{}    // Not emitting: ERROR:  Failed to resolve JNI path to Rust path for {}", indent, exception)?; continue; },
+            };
+            if variant == "Other" || variants.iter().any(|(v, _, _)| *v == variant) {
+                writeln!(out, "{}    // Not emitting: Variant name for {} is already taken", indent, exception)?;
+                continue;
+            }
+            let variant_cfg = Struct::feature_for(context, id).ok().map(|f| format!("#[cfg(any(feature = \"all\", feature = {:?}))]", f));
+
+            writeln!(out, "{}    /// {}", indent, exception.replace(['/', '$'], "."))?;
+            if let Some(variant_cfg) = variant_cfg.as_ref() { writeln!(out, "{}    {}", indent, variant_cfg)?; }
+            writeln!(out, "{}    {}(__jni_bindgen::Local<'env, {}>),", indent, &variant, &path)?;
+            variants.push((variant, path, variant_cfg));
+        }
+        writeln!(out, "{}    /// Anything else, such as unchecked exceptions.", indent)?;
+        writeln!(out, "{}    Other(__jni_bindgen::Local<'env, {}>),", indent, throwable)?;
+        writeln!(out, "{}}}", indent)?;
+
+        writeln!(out, "")?;
+        if let Some(cfg) = cfg.as_ref() { writeln!(out, "{}{}", indent, cfg)?; }
+        writeln!(out, "{}impl<'env> __jni_bindgen::std::convert::From<__jni_bindgen::Local<'env, {}>> for {}<'env> {{", indent, throwable, enum_name)?;
+        writeln!(out, "{}    fn from(throwable: __jni_bindgen::Local<'env, {}>) -> Self {{", indent, throwable)?;
+        for (variant, path, variant_cfg) in variants.iter() {
+            if let Some(variant_cfg) = variant_cfg.as_ref() { writeln!(out, "{}        {}", indent, variant_cfg)?; }
+            writeln!(out, "{}        let throwable = match throwable.try_cast::<{}>() {{ Ok(e) => return {}::{}(e), Err(t) => t }};", indent, path, enum_name, variant)?;
+        }
+        writeln!(out, "{}        {}::Other(throwable)", indent, enum_name)?;
+        writeln!(out, "{}    }}", indent)?;
+        writeln!(out, "{}}}", indent)?;
+
+        // Not #[derive(Debug)]:  the declared exceptions' structs (and possibly throwable_type) don't implement Debug.
+        writeln!(out, "")?;
+        if let Some(cfg) = cfg.as_ref() { writeln!(out, "{}{}", indent, cfg)?; }
+        writeln!(out, "{}impl<'env> __jni_bindgen::std::fmt::Debug for {}<'env> {{", indent, enum_name)?;
+        writeln!(out, "{}    fn fmt(&self, f: &mut __jni_bindgen::std::fmt::Formatter<'_>) -> __jni_bindgen::std::fmt::Result {{", indent)?;
+        writeln!(out, "{}        let (variant, throwable) : (&str, &{}) = match self {{", indent, throwable)?;
+        for (variant, _path, variant_cfg) in variants.iter() {
+            if let Some(variant_cfg) = variant_cfg.as_ref() { writeln!(out, "{}            {}", indent, variant_cfg)?; }
+            writeln!(out, "{}            {}::{}(e) => ({:?}, e),", indent, enum_name, variant, variant)?;
+        }
+        writeln!(out, "{}            {}::Other(e) => (\"Other\", e),", indent, enum_name)?;
+        writeln!(out, "{}        }};", indent)?;
+        writeln!(out, "{}        f.debug_tuple(variant).field(&__jni_bindgen::ThrowableDetails::of(throwable)).finish()", indent)?;
+        writeln!(out, "{}    }}", indent)?;
+        writeln!(out, "{}}}", indent)?;
+    }
+    Ok(())
+}
+
@@ -140,6 +140,11 @@ impl<'a> Field<'a> {
             });
         }
 
+        let static_env = context.config.codegen.static_env;
+        if self.java.is_static() && static_env == config::toml::StaticEnvStyle::__NonExhaustive {
+            emit_reject_reasons.push("ERROR:  StaticEnvStyle::__NonExhaustive is invalid, silly goose!");
+        }
+
         let emit_reject_reasons = emit_reject_reasons; // Freeze
         let indent = if emit_reject_reasons.is_empty() {
             format!("{}        ", indent)
@@ -166,7 +171,16 @@ impl<'a> Field<'a> {
             writeln!(out, "{}// Not emitting: {}", indent, reason)?;
         }
 
-        let env_param = if self.java.is_static() { "env: &'env __jni_bindgen::Env" } else { "&'env self" };
+        let env_param = match (self.java.is_static(), static_env) {
+            (false, _)                                      => "&'env self",
+            (true,  config::toml::StaticEnvStyle::Implicit) => "",
+            (true,  _)                                      => "env: &'env __jni_bindgen::Env",
+        };
+        let env_let = match (self.java.is_static(), static_env) {
+            (false, _)                                      => Some("let env = __jni_bindgen::Env::from_ptr(self.0.env);"),
+            (true,  config::toml::StaticEnvStyle::Implicit) => Some("let env = __jni_bindgen::Env::from_implicit_vm();"),
+            (true,  _)                                      => None,
+        };
 
         let url = KnownDocsUrl::from_field(context, self.class.path.as_str(), self.java.name.as_str(), self.java.descriptor());
         let url = url.as_ref();
@@ -195,15 +209,24 @@ impl<'a> Field<'a> {
                 } else {
                     writeln!(out, "{}/// **get** {} {}", indent, &keywords, self.java.name.as_str())?;
                 }
+                // Implicit envs have no lifetime to tie 'env to - callers must uphold it themselves.  See Env::from_implicit_vm.
+                let unsafe_get = self.java.is_static() && static_env == config::toml::StaticEnvStyle::Implicit && field_fragment == "object";
+                if unsafe_get {
+                    writeln!(out, "{}///", indent)?;
+                    writeln!(out, "{}/// # Safety", indent)?;
+                    writeln!(out, "{}///", indent)?;
+                    writeln!(out, "{}/// Uses the implicit VM's env:  the returned reference must not outlive the current thread's local frame (e.g. the native method you're called from.)", indent)?;
+                }
                 if let Some(required_feature) = required_feature.as_ref() {
                     writeln!(out, "{}///", indent)?;
                     writeln!(out, "{}/// Required feature: {:?}", indent, required_feature)?;
                     writeln!(out, "{}#[cfg(any(feature = \"all\", feature = {:?}))]", indent, required_feature)?;
                 }
-                writeln!(out, "{}{}pub fn {}<'env>({}) -> {} {{", indent, &attributes, get, env_param, rust_get_type)?;
+                let lifetimes = if env_param.is_empty() && field_fragment != "object" { "" } else { "<'env>" }; // Implicit primitive getters don't use 'env
+                writeln!(out, "{}{}pub {}fn {}{}({}) -> {} {{", indent, &attributes, if unsafe_get { "unsafe " } else { "" }, get, lifetimes, env_param, rust_get_type)?;
                 writeln!(out, "{}    unsafe {{", indent)?;
-                if !self.java.is_static() {
-                    writeln!(out, "{}        let env = __jni_bindgen::Env::from_ptr(self.0.env);", indent)?;
+                if let Some(env_let) = env_let {
+                    writeln!(out, "{}        {}", indent, env_let)?;
                 }
                 writeln!(out, "{}        let (__jni_class, __jni_field) = env.require_class_{}field({}, {}, {});", indent, if self.java.is_static() { "static_" } else { "" }, emit_cstr(self.class.path.as_str()), emit_cstr(self.java.name.as_str()), emit_cstr(self.java.descriptor_str()) )?;
                 if self.java.is_static() {
@@ -216,7 +239,12 @@ impl<'a> Field<'a> {
 
                 // Setter
                 if !self.java.is_final() {
-                    let lifetimes = if field_fragment == "object" { "'env, 'obj" } else { "'env" };
+                    let lifetimes = match (field_fragment == "object", env_param.is_empty()) {
+                        (true,  false)  => "<'env, 'obj>",
+                        (true,  true)   => "<'obj>",
+                        (false, false)  => "<'env>",
+                        (false, true)   => "", // Implicit setters don't use 'env
+                    };
 
                     writeln!(out, "")?;
                     if let Some(url) = url {
@@ -229,10 +257,10 @@ impl<'a> Field<'a> {
                         writeln!(out, "{}/// Required feature: {:?}", indent, required_feature)?;
                         writeln!(out, "{}#[cfg(any(feature = \"all\", feature = {:?}))]", indent, required_feature)?;
                     }
-                    writeln!(out, "{}{}pub fn {}<{}>({}, value: {}) {{", indent, &attributes, set, lifetimes, env_param, rust_set_type)?;
+                    writeln!(out, "{}{}pub fn {}{}({}value: {}) {{", indent, &attributes, set, lifetimes, if env_param.is_empty() { String::new() } else { format!("{}, ", env_param) }, rust_set_type)?;
                     writeln!(out, "{}    unsafe {{", indent)?;
-                    if !self.java.is_static() {
-                        writeln!(out, "{}        let env = __jni_bindgen::Env::from_ptr(self.0.env);", indent)?;
+                    if let Some(env_let) = env_let {
+                        writeln!(out, "{}        {}", indent, env_let)?;
                     }
                     writeln!(out, "{}        let (__jni_class, __jni_field) = env.require_class_{}field({}, {}, {});", indent, if self.java.is_static() { "static_" } else { "" }, emit_cstr(self.class.path.as_str()), emit_cstr(self.java.name.as_str()), emit_cstr(self.java.descriptor_str()) )?;
                     if self.java.is_static() {
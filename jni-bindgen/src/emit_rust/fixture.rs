@@ -0,0 +1,164 @@
+//! Golden test for the bindings checked into `jni-bindgen-fixture/src/bindings.rs`.
+//!
+//! The fixture crate is a workspace member, so building the workspace also checks that the generated natives traits,
+//! export macros, etc. actually compile against jni-glue.  If you change what jni-bindgen emits, regenerate it with:
+//!
+//! ```text
+//! JNI_BINDGEN_BLESS=1 cargo test -p jni-bindgen
+//! ```
+//!
+//! The same classes are also generated with `static_env = "implicit"` and `string_params = "into_java_string"` into
+//! `jni-bindgen-fixture/tests/implicit/bindings.rs`, which `jni-bindgen-fixture/tests/implicit.rs` compiles.
+
+use crate::emit_rust::*;
+use crate::config::{runtime::Config, toml};
+
+use jreflection::{class, field, method, Class};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+
+
+const FIXTURE_TOML : &str = r#"
+    [codegen]
+    throwable_type      = "crate::java::lang::Throwable"
+    shard_structs       = false
+    feature_per_struct  = false
+    native_traits       = true
+    proxy_traits        = true
+    exception_enums     = "per_class"
+
+    [input]
+    files = []
+
+    [output]
+    path = "src/bindings.rs"
+"#;
+
+const IMPLICIT_FIXTURE_TOML : &str = r#"
+    [codegen]
+    static_env          = "implicit"
+    string_params       = "into_java_string"
+    throwable_type      = "crate::java::lang::Throwable"
+    shard_structs       = false
+    feature_per_struct  = false
+
+    [input]
+    files = []
+
+    [output]
+    path = "tests/implicit/bindings.rs"
+"#;
+
+const RUST_LIB_GLUE : &str = r#"
+    [class]
+    path            = "com/example/RustLib"
+
+    [[native]]
+    name            = "add"
+    signature       = "(II)I"
+    static          = true
+
+    [[native]]
+    name            = "describe"
+    signature       = "(Ljava/lang/Object;)Ljava/lang/String;"
+
+    [[native]]
+    name            = "describe"
+    signature       = "(I)Ljava/lang/String;"
+
+    [[native]]
+    name            = "get_count"
+    signature       = "()I"
+"#;
+
+fn class(flags: class::Flags, path: &str, super_path: Option<&str>, methods: &[(method::Flags, &str, &str)]) -> Class {
+    Class {
+        flags,
+        path:       class::IdBuf::new(path.to_owned()),
+        super_path: super_path.map(|path| class::IdBuf::new(path.to_owned())),
+        methods:    methods.iter().map(|&(flags, name, descriptor)| method::Method::new(flags, name.to_owned(), descriptor.to_owned()).unwrap()).collect(),
+        ..Default::default()
+    }
+}
+
+fn fields(flags: field::Flags, fields: &[(&str, &str)]) -> Vec<field::Field> {
+    fields.iter().map(|&(name, descriptor)| field::Field::new(flags, name.to_owned(), descriptor.to_owned()).unwrap()).collect()
+}
+
+/// A `throws` map, keyed by `"{name}\x1f{descriptor}"` like `read_method_throws` reads them.
+fn throws(methods: &[(&str, &str, &[&str])]) -> HashMap<String, Vec<String>> {
+    methods.iter().map(|&(name, descriptor, exceptions)| (format!("{}\x1f{}", name, descriptor), exceptions.iter().map(|&e| e.to_owned()).collect())).collect()
+}
+
+/// The classes to generate bindings for, and what each of their methods are declared to throw.
+fn fixture_classes() -> Vec<(Class, HashMap<String, Vec<String>>)> {
+    let public      = class::Flags::PUBLIC | class::Flags::SUPER;
+    let interface   = class::Flags::PUBLIC | class::Flags::INTERFACE | class::Flags::ABSTRACT;
+    let ctor        = (method::Flags::PUBLIC, "<init>", "()V");
+    let abstract_   = method::Flags::PUBLIC | method::Flags::ABSTRACT;
+    let object      = Some("java/lang/Object");
+    let throwable   = Some("java/lang/Throwable");
+
+    vec![
+        (class(public, "java/lang/Object", None, &[ctor]), HashMap::new()),
+        (class(public | class::Flags::FINAL, "java/lang/String", object, &[ctor]), HashMap::new()),
+        (class(public, "java/lang/Throwable", object, &[ctor]), HashMap::new()),
+        (crate::config::glue::File::read_str(RUST_LIB_GLUE).unwrap().to_class().unwrap(), HashMap::new()),
+        (class(interface, "com/example/Listener", object, &[
+            (abstract_, "onValue",  "(I)I"),
+            (abstract_, "onValue",  "(J)J"),
+            (abstract_, "describe", "(Ljava/lang/Object;)Ljava/lang/String;"),
+            (abstract_, "fire",     "()V"),
+        ]), HashMap::new()),
+        (class(public, "com/example/ParseException",  throwable, &[ctor]), HashMap::new()),
+        (class(public, "com/example/FormatException", throwable, &[ctor]), HashMap::new()),
+        (class(public, "com/example/Parser", object, &[
+            ctor,
+            (method::Flags::PUBLIC, "parse",  "(Ljava/lang/String;)I"),
+            (method::Flags::PUBLIC, "format", "(I)Ljava/lang/String;"),
+        ]), throws(&[
+            ("parse",  "(Ljava/lang/String;)I", &["com/example/ParseException", "java/lang/Throwable"]),
+            ("format", "(I)Ljava/lang/String;", &["com/example/FormatException", "com/example/ParseException"]),
+        ])),
+        (Class {
+            fields: fields(field::Flags::PUBLIC | field::Flags::STATIC, &[("count", "I"), ("label", "Ljava/lang/String;")]),
+            ..class(public, "com/example/Settings", object, &[
+                (method::Flags::PUBLIC, "<init>", "(Ljava/lang/String;)V"),
+                (method::Flags::PUBLIC | method::Flags::STATIC, "getName", "()Ljava/lang/String;"),
+                (method::Flags::PUBLIC | method::Flags::STATIC, "setName", "(Ljava/lang/String;)V"),
+                (method::Flags::PUBLIC, "describe", "(Ljava/lang/String;I)Ljava/lang/String;"),
+            ])
+        }, HashMap::new()),
+    ]
+}
+
+fn check_fixture(toml: &str) {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("../jni-bindgen-fixture");
+    let config : Config = toml::FileWithContext { file: toml::File::read_str(toml).unwrap(), directory: fixture.clone() }.into();
+    let files = util::ConcurrentDedupeFileSet::new();
+    let mut context = Context::new(&files, &config);
+    for (class, throws) in fixture_classes() {
+        context.add_struct(class, throws).unwrap();
+    }
+
+    let mut out = Vec::new();
+    context.write(&mut out).unwrap();
+
+    let path = config.output_path.clone();
+    if std::env::var_os("JNI_BINDGEN_BLESS").is_some() {
+        fs::write(&path, &out[..]).unwrap();
+    }
+    let expected = fs::read(&path).unwrap_or_default();
+    assert!(expected == out, "{} is out of date - regenerate it with JNI_BINDGEN_BLESS=1 cargo test -p jni-bindgen", path.display());
+}
+
+#[test] fn write_fixture_bindings() {
+    check_fixture(FIXTURE_TOML);
+}
+
+#[test] fn write_implicit_fixture_bindings() {
+    check_fixture(IMPLICIT_FIXTURE_TOML);
+}
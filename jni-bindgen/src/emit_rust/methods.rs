@@ -11,6 +11,7 @@ use std::io;
 pub struct Method<'a> {
     pub class:      &'a jreflection::Class,
     pub java:       &'a jreflection::Method,
+    pub throws:     &'a [String],
     rust_name:      Option<String>,
     mangling_style: MethodManglingStyle,
 }
@@ -20,6 +21,7 @@ impl<'a> Method<'a> {
         let mut result = Self {
             class,
             java,
+            throws:         &[],
             rust_name:      None,
             mangling_style: MethodManglingStyle::Java, // Immediately overwritten bellow
         };
@@ -40,6 +42,17 @@ impl<'a> Method<'a> {
         };
     }
 
+    /// The [[rename]]d name of the method if any, or else rust_name()
+    pub fn configured_rust_name(&self, context: &Context) -> Option<String> {
+        let java_class_method       = format!("{}\x1f{}", self.class.path.as_str(), &self.java.name);
+        let java_class_method_sig   = format!("{}\x1f{}\x1f{}", self.class.path.as_str(), &self.java.name, self.java.descriptor_str());
+
+        let renamed_to = context.config.rename_class_methods    .get(&java_class_method)
+            .or_else(||  context.config.rename_class_method_sigs.get(&java_class_method_sig));
+
+        renamed_to.cloned().or_else(|| self.rust_name().map(|name| name.to_owned()))
+    }
+
     pub fn emit(&self, context: &Context, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
         let mut emit_reject_reasons = Vec::new();
         let mut required_features = BTreeSet::new();
@@ -51,15 +64,10 @@ impl<'a> Method<'a> {
             context.config.ignore_class_methods    .contains(&java_class_method) ||
             context.config.ignore_class_method_sigs.contains(&java_class_method_sig);
 
-        let renamed_to = context.config.rename_class_methods    .get(&java_class_method)
-            .or_else(||  context.config.rename_class_method_sigs.get(&java_class_method_sig));
-
         let descriptor = self.java.descriptor();
 
-        let method_name = if let Some(renamed_to) = renamed_to {
-            renamed_to.clone()
-        } else if let Some(name) = self.rust_name() {
-            name.to_owned()
+        let method_name = if let Some(name) = self.configured_rust_name(context) {
+            name
         } else {
             emit_reject_reasons.push("ERROR:  Failed to mangle method name");
             self.java.name.to_owned()
@@ -74,11 +82,13 @@ impl<'a> Method<'a> {
         // https://docs.oracle.com/javase/tutorial/reflect/member/methodparameterreflection.html
 
         let mut params_array = String::new(); // Contents of let __jni_args = [...];
+        let mut string_params = Vec::new(); // Parameters to convert via IntoJavaString before building __jni_args
 
         // Contents of fn name<'env>(...) {
         let mut params_decl = if self.java.is_constructor() || self.java.is_static() {
             match context.config.codegen.static_env {
                 config::toml::StaticEnvStyle::Explicit => String::from("__jni_env: &'env __jni_bindgen::Env"),
+                config::toml::StaticEnvStyle::Implicit => String::new(),
                 config::toml::StaticEnvStyle::__NonExhaustive => {
                     emit_reject_reasons.push("ERROR:  StaticEnvStyle::__NonExhaustive is invalid, silly goose!");
                     String::new()
@@ -92,6 +102,7 @@ impl<'a> Method<'a> {
             let arg_name = format!("arg{}", arg_idx);
 
             let mut param_is_object = false; // XXX
+            let mut param_is_string = false;
 
             let arg_type = match arg {
                 method::Type::Single(method::BasicType::Void) => {
@@ -113,7 +124,15 @@ impl<'a> Method<'a> {
                         emit_reject_reasons.push("ERROR:  Unable to resolve class feature");
                     }
                     param_is_object = true;
+                    if class.as_str() == "java/lang/String" {
+                        match context.config.codegen.string_params {
+                            config::toml::StringParamStyle::IntoOption      => {},
+                            config::toml::StringParamStyle::IntoJavaString  => param_is_string = true,
+                            config::toml::StringParamStyle::__NonExhaustive => emit_reject_reasons.push("ERROR:  StringParamStyle::__NonExhaustive is invalid, silly goose!"),
+                        }
+                    }
                     match context.java_to_rust_path(class) {
+                        Ok(path) if param_is_string => format!("impl __jni_bindgen::IntoJavaString<'env, {}>", path),
                         Ok(path) => format!("impl __jni_bindgen::std::convert::Into<__jni_bindgen::std::option::Option<&'env {}>>", path),
                         Err(_) => {
                             emit_reject_reasons.push("ERROR:  Failed to resolve JNI path to Rust path for argument type");
@@ -177,7 +196,11 @@ impl<'a> Method<'a> {
             params_array.push_str("__jni_bindgen::AsJValue::as_jvalue(");
             params_array.push_str("&");
             params_array.push_str(arg_name.as_str());
-            if param_is_object { params_array.push_str(".into()"); }
+            if param_is_string {
+                string_params.push(arg_name.clone());
+            } else if param_is_object {
+                params_array.push_str(".into()");
+            }
             params_array.push_str(")");
 
             if !params_decl.is_empty() {
@@ -302,7 +325,14 @@ impl<'a> Method<'a> {
             }
             format!("{}        // ", indent)
         };
-        let access = if self.java.is_public() { "pub " } else { "" };
+        // Implicit envs have no lifetime to tie 'env to - callers must uphold it themselves.  See Env::from_implicit_vm.
+        let implicit_env = (self.java.is_constructor() || self.java.is_static()) && context.config.codegen.static_env == config::toml::StaticEnvStyle::Implicit;
+        let access = match (self.java.is_public(), implicit_env) {
+            (true,  false)  => "pub ",
+            (true,  true)   => "pub unsafe ",
+            (false, false)  => "",
+            (false, true)   => "unsafe ",
+        };
         let attributes = format!("{}",
             if self.java.deprecated { "#[deprecated] " } else { "" }
         );
@@ -318,6 +348,12 @@ impl<'a> Method<'a> {
         } else {
             writeln!(out, "{}/// {}", indent, self.java.name.as_str())?;
         }
+        if implicit_env {
+            writeln!(out, "{}///", indent)?;
+            writeln!(out, "{}/// # Safety", indent)?;
+            writeln!(out, "{}///", indent)?;
+            writeln!(out, "{}/// Uses the implicit VM's env:  the returned references must not outlive the current thread's local frame (e.g. the native method you're called from.)", indent)?;
+        }
         if required_features.len() > 0 {
             // Feature doc comments
             writeln!(out, "{}///", indent)?;
@@ -340,27 +376,36 @@ impl<'a> Method<'a> {
             }
             writeln!(out, ")))]")?;
         }
-        writeln!(out, "{}{}{}fn {}<'env>({}) -> __jni_bindgen::std::result::Result<{}, __jni_bindgen::Local<'env, {}>> {{", indent, attributes, access, method_name, params_decl, ret_decl, context.config.codegen.throwable_type.as_str())?;
+        let throwable = context.config.codegen.throwable_type.as_str();
+        let (err_decl, map_err) = match exception_enum_name(context, self) {
+            Some(name)  => (format!("{}<'env>", name), format!(".map_err(|e: __jni_bindgen::Local<'env, {}>| e.into())", throwable)),
+            None        => (format!("__jni_bindgen::Local<'env, {}>", throwable), String::new()),
+        };
+        writeln!(out, "{}{}{}fn {}<'env>({}) -> __jni_bindgen::std::result::Result<{}, {}> {{", indent, attributes, access, method_name, params_decl, ret_decl, err_decl)?;
         writeln!(out, "{}    // class.path == {:?}, java.flags == {:?}, .name == {:?}, .descriptor == {:?}", indent, &self.class.path.as_str(), self.java.flags, &self.java.name, &self.java.descriptor_str())?;
         writeln!(out, "{}    unsafe {{", indent)?;
-        writeln!(out, "{}        let __jni_args = [{}];", indent, params_array)?;
         if self.java.is_constructor() || self.java.is_static() {
             match context.config.codegen.static_env {
                 config::toml::StaticEnvStyle::Explicit          => {},
-                config::toml::StaticEnvStyle::__NonExhaustive   => writeln!(out, "{}    let __jni_env = ...?;", indent)?, // XXX
+                config::toml::StaticEnvStyle::Implicit          => writeln!(out, "{}        let __jni_env = __jni_bindgen::Env::from_implicit_vm();", indent)?,
+                config::toml::StaticEnvStyle::__NonExhaustive   => {}, // Already rejected above
             };
         } else {
             writeln!(out, "{}        let __jni_env = __jni_bindgen::Env::from_ptr(self.0.env);", indent)?;
         }
+        for arg_name in &string_params {
+            writeln!(out, "{}        let {} = __jni_bindgen::IntoJavaString::into_java_string({}, __jni_env);", indent, arg_name, arg_name)?;
+        }
+        writeln!(out, "{}        let __jni_args = [{}];", indent, params_array)?;
 
         writeln!(out, "{}        let (__jni_class, __jni_method) = __jni_env.require_class_{}method({}, {}, {});", indent, if self.java.is_static() { "static_" } else { "" }, emit_cstr(self.class.path.as_str()), emit_cstr(self.java.name.as_str()), emit_cstr(self.java.descriptor_str()) )?;
 
         if self.java.is_constructor() {
-            writeln!(out, "{}        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr())", indent)?;
+            writeln!(out, "{}        __jni_env.new_object_a(__jni_class, __jni_method, __jni_args.as_ptr()){}", indent, map_err)?;
         } else if self.java.is_static() {
-            writeln!(out, "{}        __jni_env.call_static_{}_method_a(__jni_class, __jni_method, __jni_args.as_ptr())", indent, ret_method_fragment)?;
+            writeln!(out, "{}        __jni_env.call_static_{}_method_a(__jni_class, __jni_method, __jni_args.as_ptr()){}", indent, ret_method_fragment, map_err)?;
         } else {
-            writeln!(out, "{}        __jni_env.call_{}_method_a(self.0.object, __jni_method, __jni_args.as_ptr())", indent, ret_method_fragment)?;
+            writeln!(out, "{}        __jni_env.call_{}_method_a(self.0.object, __jni_method, __jni_args.as_ptr()){}", indent, ret_method_fragment, map_err)?;
         }
         writeln!(out, "{}    }}", indent)?;
         writeln!(out, "{}}}", indent)?;
@@ -0,0 +1,331 @@
+use crate::emit_rust::*;
+use crate::identifiers::*;
+
+use jreflection::method;
+
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+
+
+
+/// Emits a `{Struct}Natives` trait for `structure`'s native methods, and a `{jni_mangled_class}_natives!` macro which
+/// exports the `extern "system" fn Java_...` symbols Java will look for, forwarding to an implementation of said trait:
+///
+/// ```ignore
+/// struct Imp;
+/// impl MainActivityNatives for Imp {
+///     fn dispatch_key_event<'env>(env: &'env Env, this: Ref<'env, MainActivity>, arg0: Option<Ref<'env, KeyEvent>>) -> bool { ... }
+/// }
+/// com_example_MainActivity_natives!(Imp);
+/// ```
+///
+/// Changing a native method's signature in Java changes the trait, breaking the Rust build instead of throwing
+/// UnsatisfiedLinkError at runtime.
+pub(crate) fn write_natives(context: &Context, structure: &Struct, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+    if !context.config.codegen.native_traits { return Ok(()); }
+
+    let mut natives : Vec<Native> = structure.java.methods.iter()
+        .filter(|m| m.is_native() && !m.is_constructor() && !m.is_static_init())
+        .map(|m| Native::new(context, structure, m))
+        .collect();
+    if natives.is_empty() { return Ok(()); }
+
+    let mut id_repeats   = HashMap::new();
+    let mut java_repeats = HashMap::new();
+    for native in &natives {
+        if let Some(name) = native.method.rust_name() { *id_repeats.entry(name.to_owned()).or_insert(0) += 1; }
+        *java_repeats.entry(native.method.java.name.clone()).or_insert(0) += 1;
+    }
+    for native in &mut natives {
+        if let Some(name) = native.method.rust_name() {
+            if *id_repeats.get(name).unwrap_or(&0) > 1 {
+                native.method.set_mangling_style(context.config.codegen.method_naming_style_collision);
+            }
+        }
+        native.overloaded = *java_repeats.get(&native.method.java.name).unwrap_or(&0) > 1;
+    }
+
+    let mut required_features = BTreeSet::new();
+    if let Ok(feature) = Struct::feature_for(context, structure.java.path.as_id()) { required_features.insert(feature); }
+    for native in &natives {
+        if native.emit_reject_reasons.is_empty() {
+            required_features.extend(native.required_features.iter().cloned());
+        }
+    }
+    let mut cfg = String::new();
+    if !required_features.is_empty() {
+        cfg.push_str("#[cfg(any(feature = \"all\", all(");
+        for (idx, feature) in required_features.iter().enumerate() {
+            if idx != 0 { cfg.push_str(", "); }
+            cfg.push_str(&format!("feature = {:?}", feature));
+        }
+        cfg.push_str(")))]");
+    }
+
+    let trait_name  = format!("{}Natives", &structure.rust.struct_name);
+    let trait_path  = format!("{}{}", &structure.rust.mod_prefix, &trait_name);
+    let macro_name  = format!("{}_natives", jni_mangle(structure.java.path.as_str()));
+
+    writeln!(out, "")?;
+    writeln!(out, "{}/// Native methods of {}.  Implement this trait, then export the implementation with `{}!(YourType);`", indent, structure.java.path.as_str(), &macro_name)?;
+    if !cfg.is_empty() { writeln!(out, "{}{}", indent, &cfg)?; }
+    writeln!(out, "{}#[allow(clippy::needless_lifetimes)] // 'env is named consistently, even where it could be elided", indent)?;
+    writeln!(out, "{}pub trait {} {{", indent, &trait_name)?;
+    for native in &natives {
+        native.emit_trait_fn(context, indent, out)?;
+    }
+    writeln!(out, "{}}}", indent)?;
+
+    writeln!(out, "")?;
+    writeln!(out, "{}/// Exports `extern \"system\"` JNI entry points for the native methods of {}, forwarding to `<$imp as {}>`.", indent, structure.java.path.as_str(), &trait_path)?;
+    if !cfg.is_empty() { writeln!(out, "{}{}", indent, &cfg)?; }
+    writeln!(out, "{}#[macro_export] macro_rules! {} {{", indent, &macro_name)?;
+    writeln!(out, "{}    ($imp:ty) => {{", indent)?;
+    for native in &natives {
+        native.emit_export_fn(context, &trait_path, indent, out)?;
+    }
+    writeln!(out, "{}    }};", indent)?;
+    writeln!(out, "{}}}", indent)?;
+    Ok(())
+}
+
+struct Native<'a> {
+    method:                 Method<'a>,
+    overloaded:             bool,
+    this:                   String,
+    params:                 Vec<String>,
+    ret:                    String,
+    required_features:      BTreeSet<String>,
+    emit_reject_reasons:    Vec<&'static str>,
+}
+
+impl<'a> Native<'a> {
+    fn new(context: &Context, structure: &'a Struct, java: &'a jreflection::Method) -> Self {
+        let mut required_features   = BTreeSet::new();
+        let mut emit_reject_reasons = Vec::new();
+
+        let this = if java.is_static() {
+            "__jni_bindgen::jni_sys::jclass".to_owned()
+        } else {
+            match context.java_to_rust_path(structure.java.path.as_id()) {
+                Ok(path) => format!("__jni_bindgen::Ref<'env, {}>", path),
+                Err(_) => {
+                    emit_reject_reasons.push("ERROR:  Failed to resolve JNI path to Rust path for this type");
+                    "???".to_owned()
+                },
+            }
+        };
+
+        let (params, ret) = implementation_types(context, java, &mut required_features, &mut emit_reject_reasons);
+
+        Self {
+            method: Method::new(context, &structure.java, java),
+            overloaded: false,
+            this,
+            params,
+            ret,
+            required_features,
+            emit_reject_reasons,
+        }
+    }
+
+    fn symbol(&self) -> String {
+        let java = self.method.java;
+        let mut symbol = format!("Java_{}_{}", jni_mangle(self.method.class.path.as_str()), jni_mangle(java.name.as_str()));
+        if self.overloaded {
+            let descriptor = java.descriptor_str();
+            let args = &descriptor[1..descriptor.find(')').unwrap_or(1)];
+            symbol.push_str("__");
+            symbol.push_str(&jni_mangle(args));
+        }
+        symbol
+    }
+
+    fn rejected_indent(&self, context: &Context, indent: &str) -> Option<String> {
+        if self.emit_reject_reasons.is_empty() {
+            Some(format!("{}    ", indent))
+        } else if context.config.codegen.keep_rejected_emits {
+            Some(format!("{}    // ", indent))
+        } else {
+            None
+        }
+    }
+
+    fn emit_trait_fn(&self, context: &Context, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+        let indent = if let Some(indent) = self.rejected_indent(context, indent) { indent } else { return Ok(()); };
+        let name = if let Some(name) = self.method.configured_rust_name(context) { name } else {
+            writeln!(out, "{}// Not emitting: ERROR:  Failed to mangle method name {:?}", indent, &self.method.java.name)?;
+            return Ok(());
+        };
+
+        let mut params_decl = format!("env: &'env __jni_bindgen::Env, {}: {}", if self.method.java.is_static() { "class" } else { "this" }, &self.this);
+        for (arg_idx, param) in self.params.iter().enumerate() {
+            params_decl.push_str(&format!(", arg{}: {}", arg_idx, param));
+        }
+
+        writeln!(out, "")?;
+        for reason in &self.emit_reject_reasons {
+            writeln!(out, "{}// Not emitting: {}", indent, reason)?;
+        }
+        writeln!(out, "{}/// {} {}{}", indent, if self.method.java.is_static() { "static native" } else { "native" }, &self.method.java.name, self.method.java.descriptor_str())?;
+        if self.ret == "()" {
+            writeln!(out, "{}fn {}<'env>({});", indent, name, params_decl)?;
+        } else {
+            writeln!(out, "{}fn {}<'env>({}) -> {};", indent, name, params_decl, &self.ret)?;
+        }
+        Ok(())
+    }
+
+    fn emit_export_fn(&self, context: &Context, trait_path: &str, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+        if !self.emit_reject_reasons.is_empty() { return Ok(()); } // Already documented by emit_trait_fn
+        let name = if let Some(name) = self.method.configured_rust_name(context) { name } else { return Ok(()); };
+        let indent = format!("{}        ", indent);
+
+        let this = if self.method.java.is_static() { "class" } else { "this" };
+        let mut params_decl = format!("env: &'env ::jni_glue::Env, {}: <{} as ::jni_glue::NativeArg<'env>>::Raw", this, macro_path(&self.this));
+        let mut params_call = format!("env, ::jni_glue::NativeArg::from_raw(env, {})", this);
+        for (arg_idx, param) in self.params.iter().enumerate() {
+            params_decl.push_str(&format!(", arg{}: <{} as ::jni_glue::NativeArg<'env>>::Raw", arg_idx, macro_path(param)));
+            params_call.push_str(&format!(", ::jni_glue::NativeArg::from_raw(env, arg{})", arg_idx));
+        }
+
+        writeln!(out, "{}#[doc(hidden)]", indent)?;
+        writeln!(out, "{}#[no_mangle]", indent)?;
+        writeln!(out, "{}#[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]", indent)?;
+        writeln!(out, "{}pub extern \"system\" fn {}<'env>({}) -> <{} as ::jni_glue::NativeReturn>::Raw {{", indent, self.symbol(), params_decl, macro_path(&self.ret))?;
+        writeln!(out, "{}    ::jni_glue::__jni_native_entry(env, move || unsafe {{ <$imp as {}>::{}({}) }})", indent, macro_path(trait_path), name, params_call)?;
+        writeln!(out, "{}}}", indent)?;
+        Ok(())
+    }
+}
+
+/// The Rust parameter and return types for implementing `java` in Rust - as a native method, proxy, etc.  Objects are
+/// accepted as `Option<Ref<'env, T>>` and returned as `Option<Local<'env, T>>`.
+pub(crate) fn implementation_types(context: &Context, java: &jreflection::Method, required_features: &mut BTreeSet<String>, emit_reject_reasons: &mut Vec<&'static str>) -> (Vec<String>, String) {
+    let descriptor = java.descriptor();
+
+    let params = descriptor.arguments().map(|arg| match arg {
+        method::Type::Single(method::BasicType::Void) => {
+            emit_reject_reasons.push("ERROR:  Void arguments aren't a thing");
+            "()".to_owned()
+        },
+        arg => match object_type(context, arg, required_features, emit_reject_reasons) {
+            Some(object) => format!("__jni_bindgen::std::option::Option<__jni_bindgen::Ref<'env, {}>>", object),
+            None => primitive_type(arg).to_owned(),
+        },
+    }).collect();
+
+    let ret = match descriptor.return_type() {
+        method::Type::Array { levels: 1, inner: method::BasicType::Void } => {
+            emit_reject_reasons.push("ERROR:  Returning arrays of void isn't a thing");
+            "???".to_owned()
+        },
+        ret => match object_type(context, ret, required_features, emit_reject_reasons) {
+            Some(object) => format!("__jni_bindgen::std::option::Option<__jni_bindgen::Local<'env, {}>>", object),
+            None => primitive_type(ret).to_owned(),
+        },
+    };
+
+    (params, ret)
+}
+
+fn primitive_type(ty: method::Type) -> &'static str {
+    match ty {
+        method::Type::Single(method::BasicType::Void)        => "()",
+        method::Type::Single(method::BasicType::Boolean)     => "bool",
+        method::Type::Single(method::BasicType::Byte)        => "i8",
+        method::Type::Single(method::BasicType::Char)        => "__jni_bindgen::jchar",
+        method::Type::Single(method::BasicType::Short)       => "i16",
+        method::Type::Single(method::BasicType::Int)         => "i32",
+        method::Type::Single(method::BasicType::Long)        => "i64",
+        method::Type::Single(method::BasicType::Float)       => "f32",
+        method::Type::Single(method::BasicType::Double)      => "f64",
+        method::Type::Single(method::BasicType::Class(_))    => "???",
+        method::Type::Array { .. }                           => "???",
+    }
+}
+
+/// The Rust path of an object or array type, or None for primitives.
+fn object_type(context: &Context, ty: method::Type, required_features: &mut BTreeSet<String>, emit_reject_reasons: &mut Vec<&'static str>) -> Option<String> {
+    let mut class_path = |class| {
+        if let Ok(feature) = Struct::feature_for(context, class) {
+            required_features.insert(feature);
+        } else {
+            emit_reject_reasons.push("ERROR:  Unable to resolve class feature");
+        }
+        match context.java_to_rust_path(class) {
+            Ok(path) => path,
+            Err(_) => {
+                emit_reject_reasons.push("ERROR:  Failed to resolve JNI path to Rust path for argument type");
+                "???".to_owned()
+            },
+        }
+    };
+
+    match ty {
+        method::Type::Single(method::BasicType::Class(class)) => Some(class_path(class)),
+        method::Type::Single(_) => None,
+        method::Type::Array { levels, inner } => {
+            let mut buffer = String::new();
+            for _ in 0..(levels-1) {
+                buffer.push_str("__jni_bindgen::ObjectArray<");
+            }
+            match inner {
+                method::BasicType::Boolean      => buffer.push_str("__jni_bindgen::BooleanArray"),
+                method::BasicType::Byte         => buffer.push_str("__jni_bindgen::ByteArray"),
+                method::BasicType::Char         => buffer.push_str("__jni_bindgen::CharArray"),
+                method::BasicType::Short        => buffer.push_str("__jni_bindgen::ShortArray"),
+                method::BasicType::Int          => buffer.push_str("__jni_bindgen::IntArray"),
+                method::BasicType::Long         => buffer.push_str("__jni_bindgen::LongArray"),
+                method::BasicType::Float        => buffer.push_str("__jni_bindgen::FloatArray"),
+                method::BasicType::Double       => buffer.push_str("__jni_bindgen::DoubleArray"),
+                method::BasicType::Class(class) => {
+                    buffer.push_str("__jni_bindgen::ObjectArray<");
+                    buffer.push_str(&class_path(class));
+                    buffer.push_str(", ");
+                    buffer.push_str(context.config.codegen.throwable_type.as_str());
+                    buffer.push_str(">");
+                },
+                method::BasicType::Void => {
+                    emit_reject_reasons.push("ERROR:  Arrays of void isn't a thing");
+                    buffer.push_str("[()]");
+                },
+            }
+            for _ in 0..(levels-1) { // ObjectArray s
+                buffer.push_str(", ");
+                buffer.push_str(context.config.codegen.throwable_type.as_str());
+                buffer.push_str(">");
+            }
+            Some(buffer)
+        },
+    }
+}
+
+/// Rewrites a path for use inside a `#[macro_export]`ed macro, which expands in the user's crate:  `crate::` becomes
+/// `$crate::`, and the (private) `__jni_bindgen::` alias becomes `::jni_glue::`.
+fn macro_path(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut rest = path;
+    while !rest.is_empty() {
+        let boundary = !matches!(result.chars().last(), Some(c) if c.is_alphanumeric() || c == '_' || c == '$');
+        if boundary && rest.starts_with("__jni_bindgen::") {
+            result.push_str("::jni_glue::");
+            rest = &rest["__jni_bindgen::".len()..];
+        } else if boundary && rest.starts_with("crate::") {
+            result.push_str("$crate::");
+            rest = &rest["crate::".len()..];
+        } else {
+            let c = rest.chars().next().unwrap();
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    result
+}
+
+#[test] fn macro_path_test() {
+    assert_eq!(macro_path("crate::android::app::Activity"), "$crate::android::app::Activity");
+    assert_eq!(macro_path("__jni_bindgen::Ref<'env, crate::android::app::Activity>"), "::jni_glue::Ref<'env, $crate::android::app::Activity>");
+    assert_eq!(macro_path("__jni_bindgen::ObjectArray<crate::Foo, jni_android_sys::java::lang::Throwable>"), "::jni_glue::ObjectArray<$crate::Foo, jni_android_sys::java::lang::Throwable>");
+    assert_eq!(macro_path("my_crate::Foo"), "my_crate::Foo");
+}
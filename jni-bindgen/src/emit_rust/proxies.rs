@@ -0,0 +1,169 @@
+use crate::emit_rust::*;
+
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+
+
+
+/// Emits a `{Struct}Proxy` trait for an interface's abstract methods, and a `{Struct}::new_proxy` constructor which
+/// implements the interface by forwarding calls to an implementation of said trait, via jni-glue's Proxy:
+///
+/// ```ignore
+/// struct Listener;
+/// impl View_OnClickListenerProxy for Listener {
+///     fn on_click<'env>(&self, env: &'env Env, arg0: Option<Ref<'env, View>>) { ... }
+/// }
+/// view.setOnClickListener(&*View_OnClickListener::new_proxy(env, Listener)?)?;
+/// ```
+///
+/// Default and superinterface methods aren't forwarded, and throw java.lang.UnsupportedOperationException.
+pub(crate) fn write_proxy(context: &Context, structure: &Struct, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+    if !context.config.codegen.proxy_traits || !structure.java.is_interface() { return Ok(()); }
+
+    let mut methods : Vec<ProxyMethod> = structure.java.methods.iter()
+        .filter(|m| m.is_abstract() && !m.is_static())
+        .map(|m| ProxyMethod::new(context, structure, m))
+        .collect();
+    if methods.is_empty() { return Ok(()); }
+
+    let mut id_repeats   = HashMap::new();
+    let mut java_repeats = HashMap::new();
+    for method in &methods {
+        if let Some(name) = method.method.rust_name() { *id_repeats.entry(name.to_owned()).or_insert(0) += 1; }
+        *java_repeats.entry(method.method.java.name.clone()).or_insert(0) += 1;
+    }
+    for method in &mut methods {
+        if let Some(name) = method.method.rust_name() {
+            if *id_repeats.get(name).unwrap_or(&0) > 1 {
+                method.method.set_mangling_style(context.config.codegen.method_naming_style_collision);
+            }
+        }
+        method.overloaded = *java_repeats.get(&method.method.java.name).unwrap_or(&0) > 1;
+    }
+
+    let mut required_features = BTreeSet::new();
+    if let Ok(feature) = Struct::feature_for(context, structure.java.path.as_id()) { required_features.insert(feature); }
+    for method in &methods {
+        if method.emit_reject_reasons.is_empty() {
+            required_features.extend(method.required_features.iter().cloned());
+        }
+    }
+    let mut cfg = String::new();
+    if !required_features.is_empty() {
+        cfg.push_str("#[cfg(any(feature = \"all\", all(");
+        for (idx, feature) in required_features.iter().enumerate() {
+            if idx != 0 { cfg.push_str(", "); }
+            cfg.push_str(&format!("feature = {:?}", feature));
+        }
+        cfg.push_str(")))]");
+    }
+
+    let struct_name = &structure.rust.struct_name;
+    let trait_name  = format!("{}Proxy", struct_name);
+    let throwable   = context.config.codegen.throwable_type.as_str();
+
+    writeln!(out, "")?;
+    writeln!(out, "{}/// Implement {} in Rust, for use with [{}::new_proxy](struct.{}.html#method.new_proxy).", indent, structure.java.path.as_str(), struct_name, struct_name)?;
+    if !cfg.is_empty() { writeln!(out, "{}{}", indent, &cfg)?; }
+    writeln!(out, "{}#[allow(clippy::needless_lifetimes)] // 'env is named consistently, even where it could be elided", indent)?;
+    writeln!(out, "{}pub trait {} : __jni_bindgen::std::marker::Send + __jni_bindgen::std::marker::Sync + 'static {{", indent, &trait_name)?;
+    for method in &methods {
+        method.emit_trait_fn(context, indent, out)?;
+    }
+    writeln!(out, "{}}}", indent)?;
+
+    writeln!(out, "")?;
+    if !cfg.is_empty() { writeln!(out, "{}{}", indent, &cfg)?; }
+    writeln!(out, "{}impl {} {{", indent, struct_name)?;
+    writeln!(out, "{}    /// Implements {} by forwarding calls to `imp`, via a java.lang.reflect.Proxy.", indent, structure.java.path.as_str())?;
+    writeln!(out, "{}    pub fn new_proxy<'env>(env: &'env __jni_bindgen::Env, imp: impl {}) -> __jni_bindgen::std::result::Result<__jni_bindgen::Local<'env, {}>, __jni_bindgen::Local<'env, {}>> {{", indent, &trait_name, struct_name, throwable)?;
+    writeln!(out, "{}        __jni_bindgen::Proxy::create(env, move |invocation: &__jni_bindgen::Invocation| {{", indent)?;
+    writeln!(out, "{}            let env = invocation.env();", indent)?;
+    writeln!(out, "{}            match invocation.method_name().as_str() {{", indent)?;
+    for method in &methods {
+        method.emit_dispatch(context, indent, out)?;
+    }
+    writeln!(out, "{}                _ => invocation.unsupported(),", indent)?;
+    writeln!(out, "{}            }}", indent)?;
+    writeln!(out, "{}        }})", indent)?;
+    writeln!(out, "{}    }}", indent)?;
+    writeln!(out, "{}}}", indent)?;
+    Ok(())
+}
+
+struct ProxyMethod<'a> {
+    method:                 Method<'a>,
+    overloaded:             bool,
+    params:                 Vec<String>,
+    ret:                    String,
+    required_features:      BTreeSet<String>,
+    emit_reject_reasons:    Vec<&'static str>,
+}
+
+impl<'a> ProxyMethod<'a> {
+    fn new(context: &Context, structure: &'a Struct, java: &'a jreflection::Method) -> Self {
+        let mut required_features   = BTreeSet::new();
+        let mut emit_reject_reasons = Vec::new();
+
+        if !java.is_public() { emit_reject_reasons.push("Non-public method"); }
+        let (params, ret) = implementation_types(context, java, &mut required_features, &mut emit_reject_reasons);
+
+        Self {
+            method: Method::new(context, &structure.java, java),
+            overloaded: false,
+            params,
+            ret,
+            required_features,
+            emit_reject_reasons,
+        }
+    }
+
+    fn emit_trait_fn(&self, context: &Context, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+        let indent = if self.emit_reject_reasons.is_empty() {
+            format!("{}    ", indent)
+        } else if context.config.codegen.keep_rejected_emits {
+            format!("{}    // ", indent)
+        } else {
+            return Ok(());
+        };
+        let name = if let Some(name) = self.method.configured_rust_name(context) { name } else {
+            writeln!(out, "{}// Not emitting: ERROR:  Failed to mangle method name {:?}", indent, &self.method.java.name)?;
+            return Ok(());
+        };
+
+        let mut params_decl = String::from("&self, env: &'env __jni_bindgen::Env");
+        for (arg_idx, param) in self.params.iter().enumerate() {
+            params_decl.push_str(&format!(", arg{}: {}", arg_idx, param));
+        }
+
+        writeln!(out, "")?;
+        for reason in &self.emit_reject_reasons {
+            writeln!(out, "{}// Not emitting: {}", indent, reason)?;
+        }
+        writeln!(out, "{}/// {}{}", indent, &self.method.java.name, self.method.java.descriptor_str())?;
+        if self.ret == "()" {
+            writeln!(out, "{}fn {}<'env>({});", indent, name, params_decl)?;
+        } else {
+            writeln!(out, "{}fn {}<'env>({}) -> {};", indent, name, params_decl, &self.ret)?;
+        }
+        Ok(())
+    }
+
+    fn emit_dispatch(&self, context: &Context, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
+        if !self.emit_reject_reasons.is_empty() { return Ok(()); } // Already documented by emit_trait_fn
+        let name = if let Some(name) = self.method.configured_rust_name(context) { name } else { return Ok(()); };
+
+        let mut args = String::from("env");
+        for arg_idx in 0..self.params.len() {
+            args.push_str(&format!(", invocation.arg({})", arg_idx));
+        }
+
+        let guard = if self.overloaded { format!(" if invocation.method_descriptor() == {:?}", self.method.java.descriptor_str()) } else { String::new() };
+        if self.ret == "()" {
+            writeln!(out, "{}                {:?}{} => {{ imp.{}({}); invocation.result(()) }},", indent, &self.method.java.name, guard, name, args)?;
+        } else {
+            writeln!(out, "{}                {:?}{} => invocation.result(imp.{}({})),", indent, &self.method.java.name, guard, name, args)?;
+        }
+        Ok(())
+    }
+}
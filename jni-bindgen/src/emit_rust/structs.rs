@@ -37,6 +37,7 @@ impl StructPaths {
 pub(crate) struct Struct {
     pub rust:   StructPaths,
     pub java:   jreflection::Class,
+    pub throws: HashMap<String, Vec<String>>, // "name\x1fdescriptor" => exception class paths
 }
 
 fn rust_id<'a>(id: &str) -> Result<&str, Box<dyn Error>> {
@@ -129,15 +130,21 @@ impl Struct {
         Ok(PathBuf::from(buf))
     }
 
-    pub(crate) fn new<'ctx>(context: &'ctx mut Context, java: jreflection::Class) -> Result<Self, Box<dyn Error>> {
+    pub(crate) fn new<'ctx>(context: &'ctx mut Context, java: jreflection::Class, throws: HashMap<String, Vec<String>>) -> Result<Self, Box<dyn Error>> {
         let rust = StructPaths::new(context, java.path.as_id())?;
 
         return Ok(Self {
             rust,
             java,
+            throws,
         });
     }
 
+    /// The exceptions `method` is declared to throw, if any.
+    pub(crate) fn throws_for(&self, method: &jreflection::Method) -> &[String] {
+        self.throws.get(&format!("{}\x1f{}", &method.name, method.descriptor_str())).map_or(&[], |t| &t[..])
+    }
+
     pub(crate) fn write(&self, context: &Context, indent: &str, out: &mut impl io::Write) -> io::Result<()> {
         writeln!(out, "")?;
 
@@ -199,6 +206,7 @@ impl Struct {
         let mut id_repeats = HashMap::new();
 
         let mut methods : Vec<Method> = self.java.methods.iter().map(|m| Method::new(context, &self.java, m)).collect();
+        for method in &mut methods { method.throws = self.throws_for(method.java); }
         let mut fields  : Vec<Field > = self.java.fields.iter().map(|f| Field::new(context, &self.java, f)).collect();
 
         for method in &methods {
@@ -238,6 +246,10 @@ impl Struct {
 
         writeln!(out, "{}    }}", indent)?;
         writeln!(out, "{}}}", indent)?;
+
+        write_natives(context, self, indent, out)?;
+        write_proxy(context, self, indent, out)?;
+        write_exception_enums(context, self, &methods, indent, out)?;
         Ok(())
     }
 }
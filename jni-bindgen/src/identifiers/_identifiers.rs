@@ -4,9 +4,11 @@
 use std::iter::*;
 
 mod field_mangling_style;
+#[path = "../../../jni-glue-macros/src/jni_mangling.rs"] mod jni_mangling; // Shared with #[jni_glue::native]
 mod method_mangling_style;
 mod rust_identifier;
 
 pub use field_mangling_style::*;
+pub use jni_mangling::*;
 pub use method_mangling_style::*;
 pub use rust_identifier::*;
@@ -2,6 +2,7 @@ use jreflection::{io_data_err, io_data_error};
 
 #[path = "android/_android.rs"]         mod android;
 #[path = "config/_config.rs"]           mod config;
+#[path = "emit_java/_emit_java.rs"]     mod emit_java;
 #[path = "emit_rust/_emit_rust.rs"]     mod emit_rust;
 #[path = "identifiers/_identifiers.rs"] mod identifiers;
 #[path = "run/_run.rs"]                 mod run;
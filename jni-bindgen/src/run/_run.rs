@@ -2,6 +2,7 @@
 
 #[allow(unused_imports)] use super::*;
 
+mod method_throws;
 mod run;
 
 pub use run::run;
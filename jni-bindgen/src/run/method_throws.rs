@@ -0,0 +1,127 @@
+use crate::*;
+
+use jni_glue::decode_modified_utf8;
+
+use std::collections::*;
+use std::io::{self, Read};
+
+
+
+/// What each method of a .class file is declared to throw, keyed by `"{name}\x1f{descriptor}"`, from the methods'
+/// [Exceptions](https://docs.oracle.com/javase/specs/jvms/se7/html/jvms-4.html#jvms-4.7.5) attributes.  jreflection
+/// currently skips these, so we make a second, shallower pass over the class file.
+pub(crate) fn read_method_throws(mut read: impl Read) -> io::Result<HashMap<String, Vec<String>>> {
+    let read = &mut read;
+    if read_u4(read)? != 0xCAFEBABE { return io_data_err!("Invalid header magic, not a class file"); }
+    let _minor_major = read_u4(read)?;
+
+    let constants_count = read_u2(read)? as usize;
+    let mut utf8s   = vec![None; constants_count];
+    let mut classes = vec![None; constants_count];
+    let mut index = 1;
+    while index < constants_count {
+        match read_u1(read)? {
+            1               => { let len = read_u2(read)? as usize; utf8s[index] = Some(read_string(read, len)?); },
+            7               => { classes[index] = Some(read_u2(read)? as usize); },
+            8 | 16 | 19 | 20=> { skip(read, 2)?; },
+            15              => { skip(read, 3)?; },
+            3 | 4 | 9 | 10 | 11 | 12 | 17 | 18 => { skip(read, 4)?; },
+            5 | 6           => { skip(read, 8)?; index += 1; }, // Longs and doubles take up two slots
+            tag             => { return io_data_err!("Unexpected constant pool tag {} at index {}", tag, index); },
+        }
+        index += 1;
+    }
+    let utf8 = |index: u16| utf8s.get(index as usize).and_then(|s| s.as_ref()).ok_or_else(|| io_data_error!("Expected a Utf8 constant at index {}", index));
+    let class = |index: u16| classes.get(index as usize).and_then(|c| *c).ok_or_else(|| io_data_error!("Expected a Class constant at index {}", index)).and_then(|name| utf8(name as u16));
+
+    let _access_flags   = read_u2(read)?;
+    let _this_class     = read_u2(read)?;
+    let _super_class    = read_u2(read)?;
+    let interfaces      = read_u2(read)? as usize;
+    skip(read, 2 * interfaces)?;
+
+    let fields = read_u2(read)?;
+    for _ in 0..fields {
+        skip(read, 6)?; // access_flags, name_index, descriptor_index
+        let attributes = read_u2(read)?;
+        for _ in 0..attributes {
+            skip(read, 2)?;
+            let len = read_u4(read)? as usize;
+            skip(read, len)?;
+        }
+    }
+
+    let mut throws = HashMap::new();
+    let methods = read_u2(read)?;
+    for _ in 0..methods {
+        let _access_flags   = read_u2(read)?;
+        let name            = utf8(read_u2(read)?)?;
+        let descriptor      = utf8(read_u2(read)?)?;
+        let attributes      = read_u2(read)?;
+        for _ in 0..attributes {
+            let attribute_name  = utf8(read_u2(read)?)?;
+            let len             = read_u4(read)? as usize;
+            if attribute_name != "Exceptions" { skip(read, len)?; continue; }
+
+            let count = read_u2(read)?;
+            let mut exceptions = Vec::with_capacity(count as usize);
+            for _ in 0..count {
+                exceptions.push(class(read_u2(read)?)?.clone());
+            }
+            throws.insert(format!("{}\x1f{}", name, descriptor), exceptions);
+        }
+    }
+
+    Ok(throws)
+}
+
+fn read_u1(read: &mut impl Read) -> io::Result<u8> { let mut b = [0u8; 1]; read.read_exact(&mut b)?; Ok(b[0]) }
+fn read_u2(read: &mut impl Read) -> io::Result<u16> { let mut b = [0u8; 2]; read.read_exact(&mut b)?; Ok(u16::from_be_bytes(b)) }
+fn read_u4(read: &mut impl Read) -> io::Result<u32> { let mut b = [0u8; 4]; read.read_exact(&mut b)?; Ok(u32::from_be_bytes(b)) }
+
+fn skip(read: &mut impl Read, len: usize) -> io::Result<()> {
+    let skipped = io::copy(&mut read.take(len as u64), &mut io::sink())?;
+    if skipped != len as u64 { return Err(io::ErrorKind::UnexpectedEof.into()); }
+    Ok(())
+}
+
+fn read_string(read: &mut impl Read, len: usize) -> io::Result<String> {
+    let mut buffer = vec![0u8; len];
+    read.read_exact(&mut buffer[..])?;
+    decode_modified_utf8(&buffer[..]).map(|s| s.into_owned()).map_err(|_| io_data_error!("Invalid modified UTF-8 in constant pool"))
+}
+
+#[test] fn read_method_throws_test() {
+    let glue = crate::config::glue::File::read_str("[class]\npath = \"com/example/Throws\"\n[[native]]\nname = \"f\"\nsignature = \"(J)D\"").unwrap();
+    let mut class = Vec::new();
+    crate::emit_java::write_class_file(&glue.to_class().unwrap(), Some("lib"), &mut class).unwrap();
+    assert_eq!(read_method_throws(&class[..]).unwrap(), HashMap::new()); // Nothing declares throws
+
+    // A hand assembled class file for:
+    //      public abstract class Throws { public abstract void f(long l) throws java.io.IOException, java.lang.Object; }
+    // (javac would reject the latter "exception", but the attribute doesn't care.)
+    let class : &[u8] = &[
+        0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x00, 0x00, 0x34,
+        0x00, 0x0C, // 11 constants
+        0x01, 0x00, 0x06, b'T', b'h', b'r', b'o', b'w', b's',                           // #1:  Utf8 "Throws"
+        0x07, 0x00, 0x01,                                                               // #2:  Class #1
+        0x01, 0x00, 0x10, b'j', b'a', b'v', b'a', b'/', b'l', b'a', b'n', b'g', b'/', b'O', b'b', b'j', b'e', b'c', b't',
+        0x07, 0x00, 0x03,                                                               // #4:  Class #3
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A,                           // #5:  Long 42 (+ #6)
+        0x01, 0x00, 0x13, b'j', b'a', b'v', b'a', b'/', b'i', b'o', b'/', b'I', b'O', b'E', b'x', b'c', b'e', b'p', b't', b'i', b'o', b'n',
+        0x07, 0x00, 0x07,                                                               // #8:  Class #7
+        0x01, 0x00, 0x01, b'f',                                                         // #9:  Utf8 "f"
+        0x01, 0x00, 0x04, b'(', b'J', b')', b'V',                                       // #10: Utf8 "(J)V"
+        0x01, 0x00, 0x0A, b'E', b'x', b'c', b'e', b'p', b't', b'i', b'o', b'n', b's',   // #11: Utf8 "Exceptions"
+        0x04, 0x21, 0x00, 0x02, 0x00, 0x04,                                             // access, this, super
+        0x00, 0x00,                                                                     // interfaces
+        0x00, 0x00,                                                                     // fields
+        0x00, 0x01,                                                                     // methods
+        0x04, 0x01, 0x00, 0x09, 0x00, 0x0A, 0x00, 0x01,                                 // public abstract f(J)V, 1 attribute
+        0x00, 0x0B, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x08, 0x00, 0x04,         // Exceptions: IOException, Object
+        0x00, 0x00,                                                                     // attributes
+    ];
+    let throws = read_method_throws(class).unwrap();
+    assert_eq!(throws.len(), 1);
+    assert_eq!(throws["f\x1f(J)V"], vec!["java/io/IOException".to_owned(), "java/lang/Object".to_owned()]);
+}
@@ -1,6 +1,9 @@
+use crate::config;
 use crate::config::runtime::*;
+use crate::emit_java;
 use crate::emit_rust;
 use crate::util;
+use super::method_throws::*;
 
 use jreflection::*;
 use lazy_static::*;
@@ -56,25 +59,59 @@ fn gather_file(context: &mut emit_rust::Context, path: &Path) -> Result<(), Box<
 
     match ext.to_string_lossy().to_ascii_lowercase().as_str() {
         "class" => {
-            let mut file = io::BufReader::new(File::open(path)?);
-            let class = Class::read(&mut file)?;
-            context.add_struct(class)?;
+            add_class(context, io::BufReader::new(File::open(path)?))?;
         },
         "jar" => {
             let mut jar = zip::ZipArchive::new(io::BufReader::new(File::open(path)?))?;
             let n = jar.len();
 
             for i in 0..n {
-                let mut file = jar.by_index(i)?;
+                let file = jar.by_index(i)?;
                 if !file.name().ends_with(".class") { continue; }
                 context.progress.lock().unwrap().update(format!("  reading {:3}/{}: {}...", i, n, file.name()).as_str());
-                let class = Class::read(&mut file)?;
-                context.add_struct(class)?;
+                add_class(context, file)?;
             }
         },
+        "toml" => {
+            let glue = config::glue::File::read_path(path)?;
+            let class = glue.to_class()?;
+            let dir = path.parent().unwrap_or(Path::new(""));
+            let load_library = glue.class.load_library.as_deref();
+
+            if let Some(java) = glue.output.java.as_ref() {
+                let mut out = Vec::new();
+                emit_java::write_java_source(&class, load_library, &mut out)?;
+                util::write_generated(context, &dir.join(java), &out[..])?;
+            }
+
+            if let Some(class_file) = glue.output.class.as_ref() {
+                let mut out = Vec::new();
+                emit_java::write_class_file(&class, load_library, &mut out)?;
+                util::write_generated_binary(context, &dir.join(class_file), &[0xCA, 0xFE, 0xBA, 0xBE], &out[..])?;
+            }
+
+            if !context.config.codegen.native_traits {
+                context.progress.lock().unwrap().force_update(format!("WARNING:  {} describes native methods, but native_traits isn't enabled", path.display()).as_str());
+            }
+            context.add_struct(class, HashMap::new())?;
+        },
         unknown => {
-            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Input files must have a '.class' or '.jar' extension, not a '.{}' extension", unknown)))?;
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Input files must have a '.class', '.jar', or '.toml' extension, not a '.{}' extension", unknown)))?;
         }
     }
     Ok(())
 }
+
+/// Reads and adds a class, along with - if exception enums are enabled - what its methods are declared to throw.
+fn add_class(context: &mut emit_rust::Context, mut read: impl io::Read) -> Result<(), Box<dyn Error>> {
+    if context.config.codegen.exception_enums == config::toml::ExceptionEnumStyle::None {
+        let class = Class::read(&mut read)?;
+        return context.add_struct(class, HashMap::new());
+    }
+
+    let mut bytes = Vec::new();
+    read.read_to_end(&mut bytes)?;
+    let class   = Class::read(&mut io::Cursor::new(&bytes[..]))?;
+    let throws  = read_method_throws(&bytes[..])?;
+    context.add_struct(class, throws)
+}
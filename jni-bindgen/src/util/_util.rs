@@ -7,5 +7,5 @@ mod progress;
 
 pub use dedupe_file_set::{ConcurrentDedupeFileSet, DedupeFileSet};
 pub use difference::Difference;
-pub use generated_file::write_generated;
+pub use generated_file::{write_generated, write_generated_binary};
 pub use progress::Progress;
@@ -51,6 +51,35 @@ pub fn write_generated(context: &emit_rust::Context, path: &impl AsRef<Path>, co
     fs::write(path, contents)
 }
 
+/// Like [write_generated], but for binary formats such as `.class` files, which have no room for MARKER_COMMENT.
+/// Instead, existing files are only overwritten if they start with `magic`.
+///
+/// [write_generated]:  fn.write_generated.html
+pub fn write_generated_binary(context: &emit_rust::Context, path: &impl AsRef<Path>, magic: &[u8], contents: &[u8]) -> io::Result<()> {
+    let path = path.as_ref();
+    let dir = path.parent().ok_or_else(|| io_data_error!("{:?} has no parent directory", path))?;
+    let _ = create_dir_all(dir);
+
+    match fs::read(path) {
+        Ok(ref original) if original[..] == contents[..] => {
+            context.progress.lock().unwrap().update(format!("unchanged: {}...", path.display()).as_str());
+            return Ok(());
+        },
+        Ok(ref original) if original.starts_with(magic) => {
+            context.progress.lock().unwrap().force_update(format!("MODIFIED: {}", path.display()).as_str());
+        },
+        Ok(_) => {
+            return io_data_err!("Cannot overwrite {:?}:  File exists, and doesn't start with the expected magic {:?}", path, magic);
+        },
+        Err(ref e) if e.kind() == ErrorKind::NotFound => {
+            context.progress.lock().unwrap().force_update(format!("NEW: {}", path.display()).as_str());
+        },
+        Err(e) => { return Err(e); },
+    };
+
+    fs::write(path, contents)
+}
+
 
 
 fn read_line_no_eol(reader: &mut impl BufRead, buffer: &mut String) -> io::Result<usize> {
@@ -0,0 +1,35 @@
+//! Shared with jni-bindgen (via `#[path]`), so the symbols `#[native]` exports always match the ones generated
+//! `{Class}Natives` export macros do.
+
+/// Escapes a class name, method name, or argument descriptor for use in a JNI native method symbol name, per
+/// <https://docs.oracle.com/javase/8/docs/technotes/guides/jni/spec/design.html#resolving_native_method_names>
+///
+/// | Java          | JNI Symbol    |
+/// | ------------- | ------------- |
+/// | /             | _             |
+/// | _             | _1            |
+/// | ;             | _2            |
+/// | \[            | _3            |
+/// | $, é, ...     | _0xxxx        |
+pub fn jni_mangle(name: &str) -> String {
+    let mut mangled = String::with_capacity(name.len());
+    for unit in name.encode_utf16() {
+        match unit {
+            0x2F /* / */ => mangled.push('_'),
+            0x5F /* _ */ => mangled.push_str("_1"),
+            0x3B /* ; */ => mangled.push_str("_2"),
+            0x5B /* [ */ => mangled.push_str("_3"),
+            unit if unit < 0x80 && (unit as u8).is_ascii_alphanumeric() => mangled.push(unit as u8 as char),
+            unit => mangled.push_str(&format!("_0{:04x}", unit)),
+        }
+    }
+    mangled
+}
+
+#[test] fn jni_mangle_test() {
+    assert_eq!(jni_mangle("com/maulingmonkey/jni_bindgen/example_android_studio/MainActivity"), "com_maulingmonkey_jni_1bindgen_example_1android_1studio_MainActivity");
+    assert_eq!(jni_mangle("dispatchKeyEvent"), "dispatchKeyEvent");
+    assert_eq!(jni_mangle("I[Ljava/lang/String;"), "I_3Ljava_lang_String_2");
+    assert_eq!(jni_mangle("Outer$Inner"), "Outer_00024Inner");
+    assert_eq!(jni_mangle("caf\u{00E9}"), "caf_000e9");
+}
@@ -0,0 +1,166 @@
+//! Procedural macros for [jni-glue](https://docs.rs/jni-glue/).  You should generally use these via their jni-glue
+//! re-exports (e.g. `#[jni_glue::native(...)]`) rather than depending on this crate directly.
+
+extern crate proc_macro;
+
+mod jni_mangling;
+use jni_mangling::jni_mangle;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::visit_mut::{self, VisitMut};
+use syn::*;
+
+
+
+/// Defines a JNI native method implementation with a safe Rust signature.
+///
+/// Exports an `extern "system"` function with the correctly escaped `Java_{class}_{method}` symbol name, which:
+///
+/// * Converts Java's raw arguments into the function's parameter types (see `NativeArg`.)
+/// * Calls the function inside `catch_unwind`, throwing panics to Java as java.lang.RuntimeException s instead of
+///   unwinding across FFI (which is undefined behavior.)
+/// * Converts the function's return value into the raw type Java expects, throwing `Err`s to Java (see
+///   `NativeReturn` and `NativeError`.)
+///
+/// The first parameter must be the `&Env`.  Instance methods take `this` as their second parameter, static methods
+/// take their `jclass`.  Lifetimes in parameter types must be spelled out (e.g. `Ref<'env, KeyEvent>`, not
+/// `Ref<KeyEvent>`.)
+///
+/// | Property                  | Required  | Description                                                           |
+/// | ------------------------- | --------- | --------------------------------------------------------------------- |
+/// | `class = "com/example/X"` | Yes       | The class declaring the native method.                                |
+/// | `name = "javaName"`       | No        | The Java method name, if different from the Rust function name.       |
+/// | `signature = "(I)Z"`      | No        | The method descriptor, to disambiguate overloaded native methods.     |
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # type KeyEvent = MainActivity;
+/// # #[repr(transparent)] pub struct MainActivity(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for MainActivity {}
+/// # unsafe impl AsJValue for MainActivity { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for MainActivity { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("MainActivity\0") } }
+/// #[jni_glue::native(class = "com/maulingmonkey/jni_bindgen/example_android_studio/MainActivity", name = "dispatchKeyEvent")]
+/// fn dispatch_key_event<'env>(env: &'env Env, this: Ref<'env, MainActivity>, key_event: Option<Ref<'env, KeyEvent>>) -> bool {
+///     // ...
+///     true
+/// }
+/// ```
+///
+/// Native methods can't be `unsafe fn`s, as nothing on the Java side would uphold their safety preconditions:
+///
+/// ```rust,compile_fail
+/// # use jni_glue::*;
+/// # use jni_sys::jclass;
+/// #[jni_glue::native(class = "com/example/Example")]
+/// unsafe fn unchecked(env: &Env, class: jclass) {}
+/// ```
+#[proc_macro_attribute]
+pub fn native(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut class     = None;
+    let mut name      = None;
+    let mut signature = None;
+    let parser = meta::parser(|meta| {
+        if      meta.path.is_ident("class")     { class     = Some(meta.value()?.parse::<LitStr>()?); Ok(()) }
+        else if meta.path.is_ident("name")      { name      = Some(meta.value()?.parse::<LitStr>()?); Ok(()) }
+        else if meta.path.is_ident("signature") { signature = Some(meta.value()?.parse::<LitStr>()?); Ok(()) }
+        else { Err(meta.error("expected `class`, `name`, or `signature`")) }
+    });
+    parse_macro_input!(attr with parser);
+    let function = parse_macro_input!(item as ItemFn);
+
+    let class = match class {
+        Some(class) => class,
+        None        => return Error::new(Span::call_site(), "missing `class = \"com/example/Class\"`").to_compile_error().into(),
+    };
+
+    match expand_native(&class, name.as_ref(), signature.as_ref(), function) {
+        Ok(tokens)  => tokens.into(),
+        Err(error)  => error.to_compile_error().into(),
+    }
+}
+
+fn expand_native(class: &LitStr, name: Option<&LitStr>, signature: Option<&LitStr>, function: ItemFn) -> Result<proc_macro2::TokenStream> {
+    let sig = &function.sig;
+    if let Some(asyncness) = sig.asyncness.as_ref()     { return Err(Error::new_spanned(asyncness, "native methods cannot be async")); }
+    if let Some(variadic)  = sig.variadic.as_ref()      { return Err(Error::new_spanned(variadic,  "native methods cannot be variadic")); }
+    if let Some(unsafety)  = sig.unsafety.as_ref()      { return Err(Error::new_spanned(unsafety,  "native methods cannot be unsafe - Java can't uphold their preconditions")); }
+    if let Some(param) = sig.generics.params.iter().find(|p| !matches!(p, GenericParam::Lifetime(_))) {
+        return Err(Error::new_spanned(param, "native methods cannot be generic over types or consts"));
+    }
+
+    let mut inputs = sig.inputs.iter();
+    match inputs.next() {
+        Some(FnArg::Typed(_)) => {}, // env: &Env
+        Some(FnArg::Receiver(receiver)) => return Err(Error::new_spanned(receiver, "native methods must be free functions")),
+        None => return Err(Error::new_spanned(sig, "native methods must take at least an `env: &Env` parameter")),
+    }
+
+    let mut arg_names = Vec::new();
+    let mut arg_types = Vec::new();
+    for (i, input) in inputs.enumerate() {
+        match input {
+            FnArg::Typed(typed) => {
+                let mut ty = (*typed.ty).clone();
+                EnvLifetime.visit_type_mut(&mut ty);
+                arg_names.push(format_ident!("__jni_arg{}", i));
+                arg_types.push(ty);
+            },
+            FnArg::Receiver(receiver) => return Err(Error::new_spanned(receiver, "native methods must be free functions")),
+        }
+    }
+
+    let return_type = match &sig.output {
+        ReturnType::Default     => parse_quote!(()),
+        ReturnType::Type(_, ty) => { let mut ty = (**ty).clone(); EnvLifetime.visit_type_mut(&mut ty); ty },
+    };
+
+    let ident = &sig.ident;
+    let method = name.map(|n| n.value()).unwrap_or_else(|| ident.to_string());
+    let mut symbol = format!("Java_{}_{}", jni_mangle(&class.value().replace('.', "/")), jni_mangle(&method));
+    if let Some(descriptor) = signature {
+        let signature = descriptor.value();
+        let args = match (signature.find('('), signature.find(')')) {
+            (Some(start), Some(end)) if start < end => &signature[start+1..end],
+            _ => return Err(Error::new_spanned(descriptor, "expected a method descriptor such as `(ILjava/lang/String;)V`")),
+        };
+        symbol.push_str("__");
+        symbol.push_str(&jni_mangle(args));
+    }
+    let symbol = Ident::new(&symbol, ident.span());
+
+    Ok(quote! {
+        #function
+
+        #[doc(hidden)]
+        #[no_mangle]
+        #[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]
+        pub extern "system" fn #symbol<'__jni_env>(
+            __jni_env: &'__jni_env ::jni_glue::Env,
+            #(#arg_names: <#arg_types as ::jni_glue::NativeArg<'__jni_env>>::Raw,)*
+        ) -> <#return_type as ::jni_glue::NativeReturn>::Raw {
+            ::jni_glue::__jni_native_entry(__jni_env, move || unsafe {
+                #ident(__jni_env, #(<#arg_types as ::jni_glue::NativeArg<'__jni_env>>::from_raw(__jni_env, #arg_names),)*)
+            })
+        }
+    })
+}
+
+/// Replaces all lifetimes (named or `'_`) with `'__jni_env`, the lifetime of the native method's env.
+struct EnvLifetime;
+
+impl VisitMut for EnvLifetime {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident != "static" {
+            *lifetime = Lifetime::new("'__jni_env", lifetime.span());
+        }
+    }
+
+    fn visit_type_reference_mut(&mut self, reference: &mut TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(Lifetime::new("'__jni_env", Span::call_site()));
+        }
+        visit_mut::visit_type_reference_mut(self, reference);
+    }
+}
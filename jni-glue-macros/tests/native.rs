@@ -0,0 +1,53 @@
+use jni_glue::*;
+use jni_sys::*;
+
+use std::ptr::null_mut;
+
+
+
+#[native(class = "com/example/Native_Test")]
+fn twice(_env: &Env, _class: jclass, value: jint) -> Result<jint, String> {
+    if value < 0 { return Err(format!("{} is negative", value)); }
+    Ok(value * 2)
+}
+
+#[native(class = "com/example/Native_Test", name = "isNull", signature = "(Ljava/lang/Object;)Z")]
+fn is_null<'env>(_env: &'env Env, _class: jclass, object: Option<Ref<'env, Throwable>>) -> bool {
+    object.is_none()
+}
+
+#[native(class = "com/example/Native_Test")]
+fn panics(_env: &Env, _class: jclass) {
+    panic!("oh no");
+}
+
+#[test] #[ignore = "requires a local JDK, see jni-glue's test_vm.rs"] fn native_entry_points() {
+    let vm = JavaVMBuilder::new().check_jni().build().unwrap();
+    vm.with_env(|env|{
+        let take_pending = || {
+            assert!(env.exception_check());
+            let jnienv = env.as_jni_env();
+            let exception = unsafe { (**jnienv).ExceptionOccurred.unwrap()(jnienv) };
+            unsafe { (**jnienv).ExceptionClear.unwrap()(jnienv) };
+            JavaException::<Throwable>::from(unsafe { Local::from_env_object(jnienv, exception) }).to_string()
+        };
+
+        assert_eq!(Java_com_example_Native_1Test_twice(env, null_mut(), 21), 42);
+        assert!(!env.exception_check());
+        assert_eq!(Java_com_example_Native_1Test_twice(env, null_mut(), -1), 0);
+        assert_eq!(take_pending(), "java.lang.RuntimeException: -1 is negative");
+
+        let string = env.new_string_from_str("not null");
+        assert_eq!(Java_com_example_Native_1Test_isNull__Ljava_lang_Object_2(env, null_mut(), null_mut()), JNI_TRUE);
+        assert_eq!(Java_com_example_Native_1Test_isNull__Ljava_lang_Object_2(env, null_mut(), string), JNI_FALSE);
+
+        Java_com_example_Native_1Test_panics(env, null_mut());
+        assert_eq!(take_pending(), "java.lang.RuntimeException: Rust panic: oh no");
+    });
+}
+
+#[repr(transparent)] struct Throwable(ObjectAndEnv);
+unsafe impl AsValidJObjectAndEnv for Throwable {}
+unsafe impl AsJValue for Throwable { fn as_jvalue(&self) -> jvalue { jvalue { l: self.0.object } } }
+unsafe impl JniType for Throwable { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Throwable\0") } }
+impl ThrowableType for Throwable {}
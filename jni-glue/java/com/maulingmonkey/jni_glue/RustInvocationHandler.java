@@ -0,0 +1,77 @@
+package com.maulingmonkey.jni_glue;
+
+import java.lang.reflect.InvocationHandler;
+import java.lang.reflect.Method;
+
+/**
+ * A java.lang.reflect.InvocationHandler backed by a Rust jni_glue::InvocationHandler.
+ *
+ * Embedded into jni-glue as RustInvocationHandler.class (see jni-glue/src/proxy.rs) and defined at runtime if it can't
+ * already be found.  On platforms without DefineClass support (e.g. Android), include this source file in your app.
+ *
+ * The Rust handler is freed by a java.lang.ref.Cleaner where available (Java 9+, Android 13+), looked up reflectively
+ * so this still compiles for Java 8 / older Android.  Otherwise it falls back on finalize().
+ */
+final class RustInvocationHandler implements InvocationHandler, Runnable {
+    private static final Object cleaner;
+    private static final Method register;
+
+    static {
+        Object c = null;
+        Method r = null;
+        try {
+            Class<?> cleanerClass = Class.forName("java.lang.ref.Cleaner");
+            c = cleanerClass.getMethod("create").invoke(null);
+            r = cleanerClass.getMethod("register", Object.class, Runnable.class);
+        } catch (Throwable t) {
+            c = null;
+            r = null;
+        }
+        cleaner = c;
+        register = r;
+    }
+
+    private final long handler;
+    private final RustInvocationHandler dropper; // null for droppers themselves
+    private long toDrop;                         // 0 except for droppers
+
+    private RustInvocationHandler(long handler) throws Exception {
+        this.handler = handler;
+        this.dropper = new RustInvocationHandler(handler, null);
+        if (cleaner != null) register.invoke(cleaner, this, dropper);
+    }
+
+    /**
+     * Creates a "dropper", which frees `handler` when run.  A separate object, as it mustn't reference the
+     * RustInvocationHandler it frees, or the Cleaner would never run it.  (Not a nested class, so jni-glue only has
+     * to embed and define a single .class file.)
+     */
+    private RustInvocationHandler(long handler, Void dropper) {
+        this.handler = handler;
+        this.dropper = null;
+        this.toDrop  = handler;
+    }
+
+    public Object invoke(Object proxy, Method method, Object[] args) throws Throwable {
+        if (method.getDeclaringClass() == Object.class) {
+            String name = method.getName();
+            if (name.equals("equals"))      return proxy == args[0];
+            if (name.equals("hashCode"))    return System.identityHashCode(proxy);
+            if (name.equals("toString"))    return "RustProxy@" + Integer.toHexString(System.identityHashCode(proxy));
+        }
+        return invoke(handler, proxy, method, args);
+    }
+
+    public synchronized void run() {
+        long handler = this.toDrop;
+        this.toDrop = 0;
+        if (handler != 0) drop(handler);
+    }
+
+    protected void finalize() {
+        if (cleaner == null && dropper != null) dropper.run();
+    }
+
+    private static native Object invoke(long handler, Object proxy, Method method, Object[] args);
+    private static native void drop(long handler);
+}
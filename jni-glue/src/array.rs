@@ -47,6 +47,70 @@ pub trait PrimitiveArray<T> where Self : Sized + AsValidJObjectAndEnv, T : Clone
     /// Uses env.Set{Type}ArrayRegion to set the contents of the java array from \[start .. start + elements.len())
     fn set_region(&self, start: usize, elements: &[T]);
 
+    /// Uses env.Get{Type}ArrayElements to get a read-only view of the java array's elements without necessarily
+    /// copying them.  This is unsafe rather than a safe RAII view, as Rust can't stop Java from writing to the array.
+    ///
+    /// # Safety
+    ///
+    /// If the VM pins the array instead of copying it, the view aliases the java array itself.  Nothing may modify the
+    /// array while the returned view is alive - not Java code on any thread, nor [set_region] (including via the arrays'
+    /// `set` and `copy_from_slice` methods), [elements_mut], or [critical_mut] on this side.
+    ///
+    /// [set_region]:   #tymethod.set_region
+    /// [elements_mut]: #tymethod.elements_mut
+    /// [critical_mut]: #tymethod.critical_mut
+    unsafe fn elements(&self) -> ArrayElements<'_, T>;
+
+    /// Uses env.Get{Type}ArrayElements to get a mutable view of the java array's elements without necessarily copying
+    /// them.  Changes are written back when the view is dropped (or [commit]ed), unless it's [abort]ed.
+    ///
+    /// # Safety
+    ///
+    /// If the VM pins the array instead of copying it, views alias the same memory.  You must not create any other
+    /// views of the same array - via this, [elements], or [critical] - while the returned view is alive.
+    ///
+    /// [commit]:   struct.ArrayElementsMut.html#method.commit
+    /// [abort]:    struct.ArrayElementsMut.html#method.abort
+    /// [elements]: #tymethod.elements
+    /// [critical]: #tymethod.critical
+    unsafe fn elements_mut(&self) -> ArrayElementsMut<'_, T>;
+
+    /// Uses env.GetPrimitiveArrayCritical to run `callback` on the java array's elements, which are very likely to be
+    /// pinned instead of copied.  The VM may block garbage collection, even on other threads, until `callback` returns -
+    /// so keep it short.
+    ///
+    /// This is unsafe rather than statically forbidding JNI calls:  no borrow or token can, since [Global]s, [Weak]s,
+    /// and [VM]s are Send + Sync, and even a capture-free fn can reach JNI through [Env::from_implicit_vm].  Requiring
+    /// `callback` to be Send still keeps it from accidentally capturing an [Env], [Local], or [Ref].
+    ///
+    /// # Safety
+    ///
+    /// `callback` must not make any JNI calls, directly or indirectly - including [VM::with_env], cloning or dropping a
+    /// [Global] or [Weak], or anything else that might attach a thread or touch a reference.  It must not block on
+    /// other threads that might make JNI calls either, lest they deadlock waiting for the critical region to end.  Java
+    /// must not modify the array while `callback` runs.
+    ///
+    /// [Env]:                      struct.Env.html
+    /// [Env::from_implicit_vm]:    struct.Env.html#method.from_implicit_vm
+    /// [Local]:                    struct.Local.html
+    /// [Ref]:                      struct.Ref.html
+    /// [Global]:                   struct.Global.html
+    /// [Weak]:                     struct.Weak.html
+    /// [VM]:                       struct.VM.html
+    /// [VM::with_env]:             struct.VM.html#method.with_env
+    unsafe fn critical<R>(&self, callback: impl FnOnce(&[T]) -> R + Send) -> R;
+
+    /// Like [critical], but `callback` may modify the elements, which are written back when it returns.
+    ///
+    /// # Safety
+    ///
+    /// Everything required by [critical], and you must not hold any other views of the same array (e.g. from
+    /// [elements]) while `callback` runs.
+    ///
+    /// [critical]: #tymethod.critical
+    /// [elements]: #tymethod.elements
+    unsafe fn critical_mut<R>(&self, callback: impl FnOnce(&mut [T]) -> R + Send) -> R;
+
     /// Uses env.New{Type}Array + Set{Type}ArrayRegion to create a new java array containing a copy of "elements".
     fn from<'env>(env: &'env Env, elements: &[T]) -> Local<'env, Self> {
         let array = Self::new(env, elements.len());
@@ -56,22 +120,7 @@ pub trait PrimitiveArray<T> where Self : Sized + AsValidJObjectAndEnv, T : Clone
 
     /// Uses env.GetArrayLength + env.Get{Type}ArrayRegion to read the contents of the java array from range into a new Vec.
     fn get_region_as_vec(&self, range: impl RangeBounds<usize>) -> Vec<T> {
-        let len = self.len();
-
-        let start = match range.start_bound() {
-            Bound::Unbounded => 0,
-            Bound::Included(n) => *n,
-            Bound::Excluded(n) => *n+1,
-        };
-
-        let end = match range.end_bound() {
-            Bound::Unbounded => len,
-            Bound::Included(n) => *n+1,
-            Bound::Excluded(n) => *n,
-        };
-
-        assert!(start <= end);
-        assert!(end   <= len);
+        let (start, end) = resolve_range(range, self.len());
         let vec_len = end - start;
 
         let mut vec = Vec::new();
@@ -86,6 +135,25 @@ pub trait PrimitiveArray<T> where Self : Sized + AsValidJObjectAndEnv, T : Clone
     }
 }
 
+/// Resolves `range` into \[start .. end) indicies, panicking if they're out of bounds for an array of length `len`.
+fn resolve_range(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Unbounded => 0,
+        Bound::Included(n) => *n,
+        Bound::Excluded(n) => *n+1,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Unbounded => len,
+        Bound::Included(n) => *n+1,
+        Bound::Excluded(n) => *n,
+    };
+
+    assert!(start <= end);
+    assert!(end   <= len);
+    (start, end)
+}
+
 // I assume jboolean as used exclusively by JNI/JVM is compatible with bool.
 // This is *not* a sound/safe assumption in the general case as jboolean can be any u8 bit pattern.
 // However, I believe this *is* a sound/safe assumption when exclusively dealing with JNI/JVM APIs which *should* be
@@ -103,7 +171,7 @@ pub trait PrimitiveArray<T> where Self : Sized + AsValidJObjectAndEnv, T : Clone
 }
 
 macro_rules! primitive_array {
-    (#[repr(transparent)] pub struct $name:ident = $type_str:expr, $type:ident { $new_array:ident $set_region:ident $get_region:ident } ) => {
+    (#[repr(transparent)] pub struct $name:ident = $type_str:expr, $type:ident { $new_array:ident $set_region:ident $get_region:ident $get_elements:ident $release_elements:ident } $(as $unsigned:ident { $from_unsigned:ident $to_unsigned:ident })? ) => {
         /// A [PrimitiveArray](trait.PrimitiveArray.html) implementation.
         #[repr(transparent)] pub struct $name(ObjectAndEnv);
 
@@ -166,18 +234,177 @@ macro_rules! primitive_array {
 
                 unsafe { (**self.0.env).$set_region.unwrap()(self.0.env as *mut _, self.0.object, start, elements_len, elements.as_ptr() as *const _) };
             }
+
+            unsafe fn elements(&self) -> ArrayElements<'_, $type> {
+                let env = self.0.env as *mut JNIEnv;
+                ArrayElements::new(env, self.0.object, self.len(),
+                    |is_copy| (**env).$get_elements.unwrap()(env, self.0.object, is_copy) as *mut c_void,
+                    |env, array, elements, mode| (**env).$release_elements.unwrap()(env, array, elements as *mut _, mode),
+                )
+            }
+
+            unsafe fn elements_mut(&self) -> ArrayElementsMut<'_, $type> {
+                let env = self.0.env as *mut JNIEnv;
+                ArrayElementsMut::new(env, self.0.object, self.len(),
+                    |is_copy| (**env).$get_elements.unwrap()(env, self.0.object, is_copy) as *mut c_void,
+                    |env, array, elements, mode| (**env).$release_elements.unwrap()(env, array, elements as *mut _, mode),
+                )
+            }
+
+            unsafe fn critical<R>(&self, callback: impl FnOnce(&[$type]) -> R + Send) -> R {
+                with_critical(self.0.env as *mut JNIEnv, self.0.object, self.len(), JNI_ABORT, |elements| callback(elements))
+            }
+
+            unsafe fn critical_mut<R>(&self, callback: impl FnOnce(&mut [$type]) -> R + Send) -> R {
+                with_critical(self.0.env as *mut JNIEnv, self.0.object, self.len(), 0, callback)
+            }
+        }
+
+        impl $name {
+            /// Uses env.New{Type}Array + Set{Type}ArrayRegion to create a new java array containing the items of "elements".
+            pub fn from_iter_in<'env>(env: &'env Env, elements: impl IntoIterator<Item = $type>) -> Local<'env, Self> {
+                let elements : Vec<$type> = elements.into_iter().collect();
+                <Self as PrimitiveArray<$type>>::from(env, &elements[..])
+            }
+
+            /// Uses env.Get{Type}ArrayRegion to read the element at "index".  Panics if "index" is out of bounds.
+            pub fn get(&self, index: usize) -> $type {
+                let mut element = [<$type>::default()];
+                self.get_region(index, &mut element[..]);
+                element[0]
+            }
+
+            /// Uses env.Set{Type}ArrayRegion to write the element at "index".  Panics if "index" is out of bounds.
+            ///
+            /// Although this only takes `&self`, it modifies the java array:  it must not be called while a view from
+            /// [elements](trait.PrimitiveArray.html#tymethod.elements) is alive, as said view may alias the array.
+            pub fn set(&self, index: usize, value: $type) {
+                self.set_region(index, &[value]);
+            }
+
+            /// Iterates over the elements of the java array, reading them a chunk at a time with env.Get{Type}ArrayRegion.
+            pub fn iter(&self) -> PrimitiveArrayIter<'_, Self, $type> {
+                PrimitiveArrayIter::new(self)
+            }
+
+            /// Uses env.Get{Type}ArrayRegion to copy the entire java array into "elements".  Panics if the lengths differ.
+            pub fn copy_to_slice(&self, elements: &mut [$type]) {
+                assert_eq!(self.len(), elements.len(), "copy_to_slice: java array and slice lengths differ");
+                self.get_region(0, elements);
+            }
+
+            /// Uses env.Set{Type}ArrayRegion to overwrite the entire java array with "elements".  Panics if the lengths differ.
+            ///
+            /// Like [set](#method.set), this must not be called while a view from
+            /// [elements](trait.PrimitiveArray.html#tymethod.elements) is alive.
+            pub fn copy_from_slice(&self, elements: &[$type]) {
+                assert_eq!(self.len(), elements.len(), "copy_from_slice: java array and slice lengths differ");
+                self.set_region(0, elements);
+            }
+
+            /// Creates a new java array containing this array's elements followed by "elements".  Java arrays can't be
+            /// resized, so this array is left unchanged.
+            pub fn extend_from<'env>(&self, env: &'env Env, elements: &[$type]) -> Local<'env, Self> {
+                let len     = self.len();
+                let array   = <Self as PrimitiveArray<$type>>::new(env, len + elements.len());
+                let mut vec = self.as_vec();
+                vec.extend_from_slice(elements);
+                array.set_region(0, &vec[..]);
+                array
+            }
+
+            /// Reads the entire java array, appending its elements to "vec".
+            pub fn extend_vec(&self, vec: &mut Vec<$type>) {
+                let start = vec.len();
+                vec.resize(start + self.len(), <$type>::default());
+                self.get_region(0, &mut vec[start..]);
+            }
+
+            $(
+                /// Like [from](trait.PrimitiveArray.html#method.from), but reinterprets the unsigned "elements" as signed java values.
+                pub fn $from_unsigned<'env>(env: &'env Env, elements: &[$unsigned]) -> Local<'env, Self> {
+                    assert_eq!(std::mem::size_of::<$unsigned>(), std::mem::size_of::<$type>());
+                    let elements = unsafe { std::slice::from_raw_parts(elements.as_ptr() as *const $type, elements.len()) };
+                    <Self as PrimitiveArray<$type>>::from(env, elements)
+                }
+
+                /// Like [as_vec](trait.PrimitiveArray.html#method.as_vec), but reinterprets the signed java values as unsigned.
+                pub fn $to_unsigned(&self) -> Vec<$unsigned> {
+                    assert_eq!(std::mem::size_of::<$unsigned>(), std::mem::size_of::<$type>());
+                    let mut vec = vec![0 as $unsigned; self.len()];
+                    self.get_region(0, unsafe { std::slice::from_raw_parts_mut(vec.as_mut_ptr() as *mut $type, vec.len()) });
+                    vec
+                }
+            )?
+        }
+
+        impl<E: ThrowableType> MultiArray<E> for [$type] {
+            type Array = $name;
+            fn new_array<'env>(&self, env: &'env Env) -> Local<'env, $name> { <$name as PrimitiveArray<$type>>::from(env, self) }
         }
     };
 }
 
-primitive_array! { #[repr(transparent)] pub struct BooleanArray = "[Z\0", bool    { NewBooleanArray SetBooleanArrayRegion GetBooleanArrayRegion } }
-primitive_array! { #[repr(transparent)] pub struct ByteArray    = "[B\0", jbyte   { NewByteArray    SetByteArrayRegion    GetByteArrayRegion    } }
-primitive_array! { #[repr(transparent)] pub struct CharArray    = "[C\0", jchar   { NewCharArray    SetCharArrayRegion    GetCharArrayRegion    } }
-primitive_array! { #[repr(transparent)] pub struct ShortArray   = "[S\0", jshort  { NewShortArray   SetShortArrayRegion   GetShortArrayRegion   } }
-primitive_array! { #[repr(transparent)] pub struct IntArray     = "[I\0", jint    { NewIntArray     SetIntArrayRegion     GetIntArrayRegion     } }
-primitive_array! { #[repr(transparent)] pub struct LongArray    = "[J\0", jlong   { NewLongArray    SetLongArrayRegion    GetLongArrayRegion    } }
-primitive_array! { #[repr(transparent)] pub struct FloatArray   = "[F\0", jfloat  { NewFloatArray   SetFloatArrayRegion   GetFloatArrayRegion   } }
-primitive_array! { #[repr(transparent)] pub struct DoubleArray  = "[D\0", jdouble { NewDoubleArray  SetDoubleArrayRegion  GetDoubleArrayRegion  } }
+primitive_array! { #[repr(transparent)] pub struct BooleanArray = "[Z\0", bool    { NewBooleanArray SetBooleanArrayRegion GetBooleanArrayRegion GetBooleanArrayElements ReleaseBooleanArrayElements } }
+primitive_array! { #[repr(transparent)] pub struct ByteArray    = "[B\0", jbyte   { NewByteArray    SetByteArrayRegion    GetByteArrayRegion    GetByteArrayElements    ReleaseByteArrayElements    } as u8 { from_bytes to_bytes } }
+primitive_array! { #[repr(transparent)] pub struct CharArray    = "[C\0", jchar   { NewCharArray    SetCharArrayRegion    GetCharArrayRegion    GetCharArrayElements    ReleaseCharArrayElements    } }
+primitive_array! { #[repr(transparent)] pub struct ShortArray   = "[S\0", jshort  { NewShortArray   SetShortArrayRegion   GetShortArrayRegion   GetShortArrayElements   ReleaseShortArrayElements   } }
+primitive_array! { #[repr(transparent)] pub struct IntArray     = "[I\0", jint    { NewIntArray     SetIntArrayRegion     GetIntArrayRegion     GetIntArrayElements     ReleaseIntArrayElements     } }
+primitive_array! { #[repr(transparent)] pub struct LongArray    = "[J\0", jlong   { NewLongArray    SetLongArrayRegion    GetLongArrayRegion    GetLongArrayElements    ReleaseLongArrayElements    } }
+primitive_array! { #[repr(transparent)] pub struct FloatArray   = "[F\0", jfloat  { NewFloatArray   SetFloatArrayRegion   GetFloatArrayRegion   GetFloatArrayElements   ReleaseFloatArrayElements   } }
+primitive_array! { #[repr(transparent)] pub struct DoubleArray  = "[D\0", jdouble { NewDoubleArray  SetDoubleArrayRegion  GetDoubleArrayRegion  GetDoubleArrayElements  ReleaseDoubleArrayElements  } }
+
+/// Iterates over the elements of a [PrimitiveArray], reading them [PrimitiveArrayIter::CHUNK] elements at a time.
+/// Changes made to the java array after a chunk has been read won't be visible until the next chunk.
+///
+/// [PrimitiveArray]:               trait.PrimitiveArray.html
+/// [PrimitiveArrayIter::CHUNK]:    #associatedconstant.CHUNK
+pub struct PrimitiveArrayIter<'a, A: PrimitiveArray<T>, T: Clone + Default> {
+    array:          &'a A,
+    buffer:         Vec<T>,
+    buffer_start:   usize,
+    index:          usize,
+    length:         usize,
+}
+
+impl<'a, A: PrimitiveArray<T>, T: Clone + Default> PrimitiveArrayIter<'a, A, T> {
+    /// How many elements are read from the java array at once.
+    pub const CHUNK : usize = 1024;
+
+    fn new(array: &'a A) -> Self {
+        Self {
+            array,
+            buffer:         Vec::new(),
+            buffer_start:   0,
+            index:          0,
+            length:         array.len(),
+        }
+    }
+}
+
+impl<'a, A: PrimitiveArray<T>, T: Clone + Default> Iterator for PrimitiveArrayIter<'a, A, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        let index = self.index;
+        if index >= self.length { return None; }
+
+        if index - self.buffer_start >= self.buffer.len() {
+            self.buffer_start = index;
+            self.buffer.resize((self.length - index).min(Self::CHUNK), T::default());
+            self.array.get_region(index, &mut self.buffer[..]);
+        }
+
+        self.index = index + 1;
+        Some(self.buffer[index - self.buffer_start].clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.length - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, A: PrimitiveArray<T>, T: Clone + Default> ExactSizeIterator for PrimitiveArrayIter<'a, A, T> {}
 
 /// A Java Array of reference types (classes, interfaces, other arrays, etc.)
 ///
@@ -192,7 +419,14 @@ unsafe impl<T: AsValidJObjectAndEnv, E: ThrowableType> AsValidJObjectAndEnv for
 
 unsafe impl<T: AsValidJObjectAndEnv, E: ThrowableType> JniType for ObjectArray<T, E> {
     fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R {
-        T::static_with_jni_type(|inner| callback(format!("[{}", inner).as_str()))
+        T::static_with_jni_type(|inner| {
+            let inner = inner.trim_end_matches('\0');
+            if inner.starts_with('[') {
+                callback(format!("[{}\0", inner).as_str())
+            } else {
+                callback(format!("[L{};\0", inner).as_str())
+            }
+        })
     }
 }
 
@@ -203,28 +437,74 @@ unsafe impl<T: AsValidJObjectAndEnv, E: ThrowableType> AsJValue for ObjectArray<
 }
 
 impl<T: AsValidJObjectAndEnv, E: ThrowableType> ObjectArray<T, E> {
+    /// Uses env.NewObjectArray to create a new java array containing "size" nulls.
     pub fn new<'env>(env: &'env Env, size: usize) -> Local<'env, Self> {
+        Self::new_with_fill(env, size, null_mut())
+    }
+
+    /// Uses env.NewObjectArray to create a new java array containing "size" references to `init`.
+    pub fn new_filled<'env, 'init>(env: &'env Env, size: usize, init: impl Into<Option<&'init T>>) -> Local<'env, Self> where T: 'init {
+        let fill = init.into().map(|v| unsafe { AsJValue::as_jvalue(v).l }).unwrap_or(null_mut());
+        Self::new_with_fill(env, size, fill)
+    }
+
+    fn new_with_fill<'env>(env: &'env Env, size: usize, fill: jobject) -> Local<'env, Self> {
         assert!(size <= std::i32::MAX as usize); // jsize == jint == i32
-        let class = Self::static_with_jni_type(|t| unsafe { env.require_class(t) });
+        let class = T::static_with_jni_type(|t| {
+            let mut t = t.trim_end_matches('\0').to_owned();
+            t.push('\0');
+            unsafe { env.require_class(&t) }
+        });
         let size = size as jsize;
         let env = env.as_jni_env();
         unsafe {
-            let fill = null_mut();
             let object = (**env).NewObjectArray.unwrap()(env, size, class, fill);
             let exception = (**env).ExceptionOccurred.unwrap()(env);
             assert!(exception.is_null()); // Only sane exception here is an OOM exception
+            (**env).DeleteLocalRef.unwrap()(env, class);
             Local::from_env_object(env, object)
         }
     }
 
+    /// Uses env.NewObjectArray + env.NewStringUTF to create a new java.lang.String\[\] containing copies of `strings`.
+    /// `T` must be your bindings' java.lang.String, or this panics.
+    pub fn from_strs<'env>(env: &'env Env, strings: &[&str]) -> Local<'env, Self> {
+        assert!(T::static_with_jni_type(|ty| ty.trim_end_matches('\0') == "java/lang/String"), "from_strs: T must be java.lang.String");
+        let array   = Self::new(env, strings.len());
+        let jnienv  = env.as_jni_env();
+        for (index, string) in strings.iter().enumerate() {
+            let string = env.new_string_from_str(string); // Panics on OOM
+            unsafe {
+                (**jnienv).SetObjectArrayElement.unwrap()(jnienv, array.0.object, index as jsize, string);
+                let exception = (**jnienv).ExceptionOccurred.unwrap()(jnienv);
+                assert!(exception.is_null()); // T is checked above, so ArrayStoreException shouldn't be possible
+                (**jnienv).DeleteLocalRef.unwrap()(jnienv, string);
+            }
+        }
+        array
+    }
+
     pub fn iter<'env>(&'env self) -> ObjectArrayIter<'env, T, E> {
+        self.iter_range(..)
+    }
+
+    /// Iterates over the elements in `range`.  Panics if `range` is out of bounds.
+    pub fn iter_range<'env>(&'env self, range: impl RangeBounds<usize>) -> ObjectArrayIter<'env, T, E> {
+        let (start, end) = resolve_range(range, self.len());
         ObjectArrayIter {
             array:  self,
-            index:  0,
-            length: self.len(),
+            index:  start,
+            length: end,
         }
     }
 
+    /// Uses env.GetObjectArrayElement to create a [Global] reference to each (non-null) element.
+    ///
+    /// [Global]:   struct.Global.html
+    pub fn to_vec(&self) -> Vec<Option<Global<T>>> {
+        self.iter().map(|element| element.map(Global::from)).collect()
+    }
+
     pub fn from<'env>(env: &'env Env, elements: impl 'env + ExactSizeIterator + Iterator<Item = impl Into<Option<&'env T>>>) -> Local<'env, Self> {
         let size    = elements.len();
         let array   = Self::new(env, size);
@@ -282,6 +562,94 @@ impl<T: AsValidJObjectAndEnv, E: ThrowableType> ObjectArray<T, E> {
             }
         }
     }
+
+    /// Uses env.GetObjectArrayElement + env.SetObjectArrayElement to swap the elements at `a` and `b`.
+    pub fn swap<'env>(&'env self, a: usize, b: usize) -> Result<(), Local<'env, E>> {
+        assert!(a <= std::i32::MAX as usize); // jsize == jint == i32
+        assert!(b <= std::i32::MAX as usize); // jsize == jint == i32
+        let env     = unsafe { Env::from_ptr(self.0.env) };
+        let jnienv  = env.as_jni_env();
+        let this    = self.0.object;
+        unsafe {
+            let first = (**jnienv).GetObjectArrayElement.unwrap()(jnienv, this, a as jsize);
+            if env.exception_check() { return Err(take_exception(env)); }
+            let second = (**jnienv).GetObjectArrayElement.unwrap()(jnienv, this, b as jsize);
+            if env.exception_check() {
+                if !first.is_null() { (**jnienv).DeleteLocalRef.unwrap()(jnienv, first); }
+                return Err(take_exception(env));
+            }
+
+            (**jnienv).SetObjectArrayElement.unwrap()(jnienv, this, a as jsize, second);
+            (**jnienv).SetObjectArrayElement.unwrap()(jnienv, this, b as jsize, first);
+            for local in [first, second].iter().filter(|l| !l.is_null()) {
+                (**jnienv).DeleteLocalRef.unwrap()(jnienv, *local);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Rust values that can be converted into a new, possibly multi-dimensional, java array - e.g. `Vec<Vec<jint>>` into
+/// an `ObjectArray<IntArray, E>` (an `int[][]`.)  Slices and `Vec`s of primitives become [PrimitiveArray]s, slices and
+/// `Vec`s of nested slices or `Vec`s become [ObjectArray]s, and slices of existing [Local] arrays (e.g. from
+/// [ObjectArray::from_strs]) may be used as the innermost dimension.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # fn example<Throwable: ThrowableType>(env: &Env) {
+/// let grid : Local<ObjectArray<IntArray, Throwable>> = vec![vec![1, 2, 3], vec![4, 5]].new_array(env);
+/// assert_eq!(grid.len(), 2);
+/// # }
+/// ```
+///
+/// [PrimitiveArray]:           trait.PrimitiveArray.html
+/// [ObjectArray]:              struct.ObjectArray.html
+/// [Local]:                    struct.Local.html
+/// [ObjectArray::from_strs]:   struct.ObjectArray.html#method.from_strs
+pub trait MultiArray<E: ThrowableType> {
+    type Array : AsValidJObjectAndEnv;
+
+    /// Creates a new java array containing a copy of `self`.
+    fn new_array<'env>(&self, env: &'env Env) -> Local<'env, Self::Array>;
+}
+
+impl<E: ThrowableType, X> MultiArray<E> for [Vec<X>] where [X]: MultiArray<E> {
+    type Array = ObjectArray<<[X] as MultiArray<E>>::Array, E>;
+    fn new_array<'env>(&self, env: &'env Env) -> Local<'env, Self::Array> { new_multi_array(env, self.iter().map(|e| e.new_array(env))) }
+}
+
+impl<E: ThrowableType, X> MultiArray<E> for [&[X]] where [X]: MultiArray<E> {
+    type Array = ObjectArray<<[X] as MultiArray<E>>::Array, E>;
+    fn new_array<'env>(&self, env: &'env Env) -> Local<'env, Self::Array> { new_multi_array(env, self.iter().map(|e| e.new_array(env))) }
+}
+
+impl<'local, E: ThrowableType, A: AsValidJObjectAndEnv> MultiArray<E> for [Local<'local, A>] {
+    type Array = ObjectArray<A, E>;
+    fn new_array<'env>(&self, env: &'env Env) -> Local<'env, Self::Array> {
+        let array = ObjectArray::<A, E>::new(env, self.len());
+        for (index, element) in self.iter().enumerate() {
+            array.set(index, &**element).unwrap_or_else(|_: Local<E>| unreachable!("element type should always match"));
+        }
+        array
+    }
+}
+
+impl<E: ThrowableType, X> MultiArray<E> for Vec<X> where [X]: MultiArray<E> {
+    type Array = <[X] as MultiArray<E>>::Array;
+    fn new_array<'env>(&self, env: &'env Env) -> Local<'env, Self::Array> { self[..].new_array(env) }
+}
+
+impl<E: ThrowableType, X: MultiArray<E> + ?Sized> MultiArray<E> for &X {
+    type Array = X::Array;
+    fn new_array<'env>(&self, env: &'env Env) -> Local<'env, Self::Array> { (**self).new_array(env) }
+}
+
+fn new_multi_array<'env, A: AsValidJObjectAndEnv, E: ThrowableType>(env: &'env Env, elements: impl ExactSizeIterator<Item = Local<'env, A>>) -> Local<'env, ObjectArray<A, E>> {
+    let array = ObjectArray::<A, E>::new(env, elements.len());
+    for (index, element) in elements.enumerate() {
+        array.set(index, &*element).unwrap_or_else(|_: Local<E>| unreachable!("element type should always match"));
+    }
+    array
 }
 
 
@@ -304,3 +672,44 @@ impl<'env, T: AsValidJObjectAndEnv, E: ThrowableType> Iterator for ObjectArrayIt
         }
     }
 }
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn primitive_array_helpers() {
+    // Single elements, iterators, slices, and unsigned bytes shouldn't need regions.
+    with_test_env(|env|{
+        let array = IntArray::from_iter_in(env, 0 .. 3000);
+        array.set(2999, -1);
+        assert_eq!((array.get(0), array.get(2999)), (0, -1));
+        assert_eq!(array.iter().len(), 3000);
+        assert_eq!(array.iter().collect::<Vec<_>>(), array.as_vec());
+        let mut copy = vec![0; 3000];
+        array.copy_to_slice(&mut copy[..]);
+        assert_eq!(copy, array.as_vec());
+        let extended = array.extend_from(env, &[7, 8]);
+        assert_eq!((extended.len(), extended.get(3001)), (3002, 8));
+        extended.copy_from_slice(&vec![5; 3002][..]);
+        let mut vec = vec![1];
+        extended.extend_vec(&mut vec);
+        assert_eq!((vec.len(), vec[0], vec[3002]), (3003, 1, 5));
+        let bytes = ByteArray::from_bytes(env, &[0, 1, 255]);
+        assert_eq!((bytes.get(2), bytes.to_bytes()), (-1, vec![0, 1, 255]));
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn object_array_helpers() {
+    // Object arrays should be creatable, fillable, and rearrangeable, including as multi-dimensional arrays.
+    with_test_env(|env|{
+        let chars   = |s: &TestString| unsafe { StringChars::from_env_jstring(env, s.0.object) }.to_string_lossy();
+        let strings = ObjectArray::<TestString, TestThrowable>::from_strs(env, &["a", "b", "c"]);
+        assert!(ObjectArray::<TestString, TestThrowable>::new(env, 2).iter().all(|e| e.is_none()));
+        let filled  = ObjectArray::<TestString, TestThrowable>::new_filled(env, 2, &*strings.get(1).unwrap().unwrap());
+        assert_eq!(filled.iter().map(|e| chars(&e.unwrap())).collect::<Vec<_>>(), vec!["b", "b"]);
+        strings.swap(0, 2).unwrap();
+        assert!(strings.swap(0, 3).is_err());
+        assert_eq!(strings.iter_range(1..).map(|e| chars(&e.unwrap())).collect::<Vec<_>>(), vec!["b", "a"]);
+        assert_eq!(strings.to_vec().iter().map(|e| chars(&e.as_ref().unwrap().with(env))).collect::<Vec<_>>(), vec!["c", "b", "a"]);
+        let grid : Local<ObjectArray<IntArray, TestThrowable>> = vec![vec![1, 2], vec![3]].new_array(env);
+        assert_eq!(grid.iter().map(|row| row.unwrap().as_vec()).collect::<Vec<_>>(), vec![vec![1, 2], vec![3]]);
+        let nested : Local<ObjectArray<ObjectArray<TestString, TestThrowable>, TestThrowable>> = MultiArray::<TestThrowable>::new_array(&[strings, filled][..], env);
+        assert_eq!(nested.get(1).unwrap().unwrap().len(), 2);
+    });
+}
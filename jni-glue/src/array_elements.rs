@@ -0,0 +1,148 @@
+use super::*;
+
+use std::ops::*;
+use std::slice;
+
+
+
+pub(crate) type ReleaseArrayElements = unsafe fn(env: *mut JNIEnv, array: jobject, elements: *mut c_void, mode: jint);
+
+/// A read-only view of a [PrimitiveArray]'s elements, acquired via env.Get{Type}ArrayElements.  Created by
+/// [PrimitiveArray::elements].
+///
+/// Depending on the VM, this may be a copy of the array, or the array itself pinned in place - which is why
+/// [PrimitiveArray::elements] is unsafe.  Either way, no copy is written back to Java when this is dropped (JNI_ABORT).
+///
+/// [PrimitiveArray]:           trait.PrimitiveArray.html
+/// [PrimitiveArray::elements]: trait.PrimitiveArray.html#tymethod.elements
+pub struct ArrayElements<'a, T> {
+    raw: RawArrayElements<'a, T>,
+}
+
+/// A mutable view of a [PrimitiveArray]'s elements, acquired via env.Get{Type}ArrayElements.  Created by
+/// [PrimitiveArray::elements_mut].
+///
+/// Depending on the VM, this may be a copy of the array, or the array itself pinned in place.  Changes are written back
+/// to Java when this is dropped, unless [abort]ed.
+///
+/// [PrimitiveArray]:               trait.PrimitiveArray.html
+/// [PrimitiveArray::elements_mut]: trait.PrimitiveArray.html#tymethod.elements_mut
+/// [abort]:                        #method.abort
+pub struct ArrayElementsMut<'a, T> {
+    raw: RawArrayElements<'a, T>,
+}
+
+struct RawArrayElements<'a, T> {
+    env:        *mut JNIEnv,
+    array:      jobject,
+    elements:   *mut T,
+    len:        usize,
+    is_copy:    bool,
+    release:    ReleaseArrayElements,
+    _array:     PhantomData<&'a ()>,
+}
+
+impl<'a, T> RawArrayElements<'a, T> {
+    unsafe fn new(env: *mut JNIEnv, array: jobject, len: usize, get: impl FnOnce(*mut jboolean) -> *mut c_void, release: ReleaseArrayElements) -> Self {
+        let mut is_copy = JNI_FALSE;
+        let elements = get(&mut is_copy) as *mut T;
+        assert!(!elements.is_null()); // Only sane failure here is an OOM
+        Self { env, array, elements, len, is_copy: is_copy != JNI_FALSE, release, _array: PhantomData }
+    }
+
+    unsafe fn release(&mut self, mode: jint) {
+        (self.release)(self.env, self.array, self.elements as *mut c_void, mode);
+    }
+}
+
+impl<'a, T> ArrayElements<'a, T> {
+    pub(crate) unsafe fn new(env: *mut JNIEnv, array: jobject, len: usize, get: impl FnOnce(*mut jboolean) -> *mut c_void, release: ReleaseArrayElements) -> Self {
+        Self { raw: RawArrayElements::new(env, array, len, get, release) }
+    }
+
+    /// Is this a copy of the array, rather than the array itself pinned in place?
+    pub fn is_copy(&self) -> bool { self.raw.is_copy }
+}
+
+impl<'a, T> ArrayElementsMut<'a, T> {
+    pub(crate) unsafe fn new(env: *mut JNIEnv, array: jobject, len: usize, get: impl FnOnce(*mut jboolean) -> *mut c_void, release: ReleaseArrayElements) -> Self {
+        Self { raw: RawArrayElements::new(env, array, len, get, release) }
+    }
+
+    /// Is this a copy of the array, rather than the array itself pinned in place?
+    pub fn is_copy(&self) -> bool { self.raw.is_copy }
+
+    /// Write any changes back to the Java array (JNI_COMMIT), while keeping this view alive.  Does nothing if
+    /// [is_copy] is false, as changes are already visible to Java.
+    ///
+    /// [is_copy]:  #method.is_copy
+    pub fn commit(&mut self) {
+        unsafe { self.raw.release(JNI_COMMIT) };
+    }
+
+    /// Release this view *without* writing any (uncommitted) changes back to the Java array (JNI_ABORT).  Note that if
+    /// [is_copy] is false, changes were already visible to Java, and cannot be undone.
+    ///
+    /// [is_copy]:  #method.is_copy
+    pub fn abort(mut self) {
+        unsafe { self.raw.release(JNI_ABORT) };
+        std::mem::forget(self);
+    }
+}
+
+impl<'a, T> Deref for ArrayElements<'a, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] { unsafe { slice::from_raw_parts(self.raw.elements, self.raw.len) } }
+}
+
+impl<'a, T> Deref for ArrayElementsMut<'a, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] { unsafe { slice::from_raw_parts(self.raw.elements, self.raw.len) } }
+}
+
+impl<'a, T> DerefMut for ArrayElementsMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut [T] { unsafe { slice::from_raw_parts_mut(self.raw.elements, self.raw.len) } }
+}
+
+impl<'a, T> Drop for ArrayElements<'a, T> {
+    fn drop(&mut self) { unsafe { self.raw.release(JNI_ABORT) } }
+}
+
+impl<'a, T> Drop for ArrayElementsMut<'a, T> {
+    fn drop(&mut self) { unsafe { self.raw.release(0) } } // Copy back (if necessary) and free
+}
+
+/// Uses env.GetPrimitiveArrayCritical to run `callback` on `array`'s elements, then releases them with `mode`.
+pub(crate) unsafe fn with_critical<T, R>(env: *mut JNIEnv, array: jobject, len: usize, mode: jint, callback: impl FnOnce(&mut [T]) -> R) -> R {
+    let elements = (**env).GetPrimitiveArrayCritical.unwrap()(env, array, null_mut()) as *mut T;
+    assert!(!elements.is_null()); // Only sane failure here is an OOM
+
+    // Don't leave the VM in a critical region (with GC potentially disabled!) if callback panics.
+    struct Release { env: *mut JNIEnv, array: jobject, elements: *mut c_void, mode: jint }
+    impl Drop for Release {
+        fn drop(&mut self) { unsafe { (**self.env).ReleasePrimitiveArrayCritical.unwrap()(self.env, self.array, self.elements, self.mode) } }
+    }
+    let _release = Release { env, array, elements: elements as *mut c_void, mode };
+
+    callback(slice::from_raw_parts_mut(elements, len))
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn array_elements_views() {
+    // Element views should see, and write back, the same contents as regions do.
+    with_test_env(|env|{
+        let array = <IntArray as PrimitiveArray<jint>>::from(env, &[1, 2, 3]);
+        assert_eq!(&*unsafe { array.elements() }, &[1, 2, 3]);
+        (unsafe { array.elements_mut() })[0] = 10;
+        let mut elements = unsafe { array.elements_mut() };
+        elements[1] = 20;
+        elements.commit();
+        elements[2] = 30;
+        elements.abort();
+        assert_eq!(array.as_vec(), if unsafe { array.elements() }.is_copy() { vec![10, 20, 3] } else { vec![10, 20, 30] });
+        assert_eq!(unsafe { array.critical(|elements| elements.iter().sum::<i32>()) }, array.as_vec().iter().sum::<i32>());
+        let mut reversed = array.as_vec();
+        reversed.reverse();
+        unsafe { array.critical_mut(|elements| elements.reverse()) };
+        assert_eq!(array.as_vec(), reversed);
+    });
+}
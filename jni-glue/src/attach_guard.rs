@@ -0,0 +1,180 @@
+use super::*;
+use std::cell::RefCell;
+use std::os::raw::c_char;
+
+
+
+/// Keeps the current thread attached to a [VM] until dropped.  Created by [VM::attach], [VM::attach_as_daemon], etc.
+///
+/// If the thread was already attached when this guard was created (e.g. because it's a thread created by Java), this
+/// guard will *not* detach it when dropped.  Otherwise, the thread is detached with DetachCurrentThread - releasing
+/// the java.lang.Thread object the VM created for it - once the last guard for the VM on this thread is dropped.
+/// Nested guards may be dropped in any order:  the thread stays attached for as long as any of them is alive.
+///
+/// Not Send: attachment is a per-thread property, so this cannot be sent to another thread.
+///
+/// [VM]:                   struct.VM.html
+/// [VM::attach]:           struct.VM.html#method.attach
+/// [VM::attach_as_daemon]: struct.VM.html#method.attach_as_daemon
+pub struct AttachGuard<'vm> {
+    vm:         &'vm VM,
+    env:        *mut JNIEnv,
+    attachment: Attachment,
+    _not_send:  PhantomData<*const ()>,
+}
+
+impl<'vm> AttachGuard<'vm> {
+    pub(crate) unsafe fn new(vm: &'vm VM, daemon: bool, name: Option<&str>, group: jobject) -> Self {
+        let java_vm = vm.as_java_vm() as *mut JavaVM;
+        let mut env = null_mut();
+        let attachment = match (**java_vm).GetEnv.unwrap()(java_vm, &mut env, JNI_VERSION_1_2) {
+            JNI_OK => Attachment::Existing,
+            JNI_EDETACHED => {
+                let name = name.map(|name| CString::new(name).expect("thread name contains an interior nul"));
+                let mut args = JavaVMAttachArgs {
+                    version:    JNI_VERSION_1_2,
+                    name:       name.as_ref().map(|name| name.as_ptr() as *mut c_char).unwrap_or(null_mut()),
+                    group,
+                };
+                let args = &mut args as *mut JavaVMAttachArgs as *mut c_void;
+                let result = if daemon {
+                    (**java_vm).AttachCurrentThreadAsDaemon.unwrap()(java_vm, &mut env, args)
+                } else {
+                    (**java_vm).AttachCurrentThread.unwrap()(java_vm, &mut env, args)
+                };
+                match result {
+                    JNI_OK => Attachment::New,
+                    unexpected => panic!("AttachCurrentThread{} returned unknown error: {}", if daemon { "AsDaemon" } else { "" }, unexpected),
+                }
+            },
+            JNI_EVERSION => panic!("GetEnv returned JNI_EVERSION"),
+            unexpected => panic!("GetEnv returned unknown error: {}", unexpected),
+        };
+
+        let vm_ptr = java_vm as *const JavaVM;
+        ATTACHED.with(|attached|{
+            let mut attached = attached.borrow_mut();
+            match attached.iter_mut().find(|a| a.vm == vm_ptr) {
+                Some(a) => { a.guards += 1; a.detach |= attachment == Attachment::New; },
+                None    => attached.push(Attached { vm: java_vm, guards: 1, detach: attachment == Attachment::New }),
+            }
+        });
+
+        Self {
+            vm,
+            env: env as *mut JNIEnv,
+            attachment,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// The current thread's [Env].
+    ///
+    /// [Env]:  struct.Env.html
+    pub fn env(&self) -> &Env {
+        unsafe { Env::from_ptr(self.env) }
+    }
+
+    /// Was the thread attached by this guard (and thus will be detached once every guard for the VM on this thread is
+    /// dropped)?
+    pub fn attachment(&self) -> Attachment {
+        self.attachment
+    }
+
+    /// Instead of detaching when dropped, keep the current thread attached until it exits, and detach it then.
+    ///
+    /// This is useful for Rust worker threads that will repeatedly call into Java - attaching is not free - but which
+    /// shouldn't leak their java.lang.Thread object forever when they eventually exit.  Has no effect if the thread
+    /// was already attached when this guard was created.
+    pub fn detach_on_thread_exit(self) {
+        if self.attachment == Attachment::New {
+            let gen_vm = self.env().get_gen_vm();
+            let java_vm = self.vm.as_java_vm() as *const JavaVM;
+            DETACH_ON_THREAD_EXIT.with(|d| d.borrow_mut().gen_vms.push(gen_vm));
+            ATTACHED.with(|attached| attached.borrow_mut().iter_mut().filter(|a| a.vm == java_vm).for_each(|a| a.detach = false));
+        }
+    }
+}
+
+impl<'vm> Drop for AttachGuard<'vm> {
+    fn drop(&mut self) {
+        let vm_ptr = self.vm.as_java_vm() as *const JavaVM;
+        let detach = ATTACHED.with(|attached|{
+            let mut attached = attached.borrow_mut();
+            let index = attached.iter().position(|a| a.vm == vm_ptr).expect("AttachGuard not tracked");
+            attached[index].guards -= 1;
+            if attached[index].guards > 0 { return false; }
+            attached.swap_remove(index).detach
+        });
+        if detach {
+            let java_vm = vm_ptr as *mut JavaVM;
+            let result = unsafe { (**java_vm).DetachCurrentThread.unwrap()(java_vm) };
+            debug_assert_eq!(result, JNI_OK);
+        }
+    }
+}
+
+
+
+/// How many [AttachGuard]s for a VM are alive on the current thread, and whether one of them attached it.
+///
+/// [AttachGuard]:  struct.AttachGuard.html
+struct Attached {
+    vm:     *const JavaVM,
+    guards: usize,
+    detach: bool,
+}
+
+thread_local! {
+    static ATTACHED : RefCell<Vec<Attached>> = const { RefCell::new(Vec::new()) };
+}
+
+
+
+struct DetachOnThreadExit {
+    gen_vms: Vec<GenVM>,
+}
+
+impl Drop for DetachOnThreadExit {
+    fn drop(&mut self) {
+        let vms = VMS.read().unwrap();
+        for gen_vm in self.gen_vms.drain(..) {
+            // If the VM has since been unloaded, there's nothing left to detach from.
+            vms.try_use_vm(gen_vm, |vm|{
+                let java_vm = vm.as_java_vm() as *mut JavaVM;
+                unsafe { (**java_vm).DetachCurrentThread.unwrap()(java_vm) };
+            });
+        }
+    }
+}
+
+thread_local! {
+    static DETACH_ON_THREAD_EXIT : RefCell<DetachOnThreadExit> = const { RefCell::new(DetachOnThreadExit { gen_vms: Vec::new() }) };
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn attach_guard_detaches() {
+    // Worker threads should be detached again when their guards are dropped.
+    let vm = test_vm();
+    std::thread::spawn(move ||{
+        let guard = vm.attach_as_daemon(Some("jni-glue worker"));
+        assert_eq!(guard.attachment(), Attachment::New);
+        assert_eq!(vm.attach().attachment(), Attachment::Existing);
+        drop(guard);
+        assert_eq!(vm.attach().attachment(), Attachment::New);
+    }).join().unwrap();
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn attach_guard_out_of_order() {
+    // Dropping the guard that attached the thread shouldn't detach it out from under nested guards.
+    let vm = test_vm();
+    std::thread::spawn(move ||{
+        let outer = vm.attach();
+        let inner = vm.attach();
+        assert_eq!(inner.attachment(), Attachment::Existing);
+        drop(outer);
+        assert_eq!(vm.attach().attachment(), Attachment::Existing);
+        let _ = inner.env().get_gen_vm();
+        drop(inner);
+        assert_eq!(vm.attach().attachment(), Attachment::New);
+    }).join().unwrap();
+}
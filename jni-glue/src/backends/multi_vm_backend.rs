@@ -0,0 +1,79 @@
+use super::*;
+
+pub(crate) struct MultiVmBackend {
+    next_gen:   usize,
+    loaded:     Vec<GenVM>,
+}
+
+impl MultiVmBackend {
+    pub const fn new() -> Self {
+        Self {
+            next_gen:   1,
+            loaded:     Vec::new(),
+        }
+    }
+
+    // Unsafe - by calling this, you assert that `vm` will be valid until you call on_unload and allow it to return.
+    pub unsafe fn on_load(&mut self, vm: *const JavaVM) {
+        assert!(!vm.is_null());
+        assert!(self.loaded.iter().all(|loaded| loaded.vm != vm));
+        let gen = self.next_gen;
+        self.next_gen += 1;
+        self.loaded.push(GenVM { gen, vm });
+    }
+
+    // Safe - only invalidates existing VMs, doesn't actually use 'em or free them.  Returns the invalidated VM.
+    pub fn on_unload(&mut self, vm: *const JavaVM) -> GenVM {
+        let index = self.loaded.iter().position(|loaded| loaded.vm == vm);
+        let index = index.expect("on_unload called for a VM that was never loaded");
+        self.loaded.swap_remove(index)
+    }
+
+    // Safe - validates against current VM state.
+    pub fn use_vm<R>(&self, vm: GenVM, callback: impl FnOnce(&VM) -> R) -> R {
+        assert!(self.loaded.contains(&vm));
+        callback(unsafe { VM::from_jni_local(&*vm.vm) })
+    }
+
+    // Safe - validates against current VM state.  Returns None if the VM has since been unloaded.
+    pub fn try_use_vm<R>(&self, vm: GenVM, callback: impl FnOnce(&VM) -> R) -> Option<R> {
+        if self.loaded.contains(&vm) {
+            Some(callback(unsafe { VM::from_jni_local(&*vm.vm) }))
+        } else {
+            None
+        }
+    }
+
+    // Safe - validates against current VM state.
+    pub fn get_gen_vm(&self, vm: *mut JavaVM) -> GenVM {
+        let vm = vm as *const JavaVM;
+        *self.loaded.iter().find(|loaded| loaded.vm == vm).expect("get_gen_vm called for a VM that isn't loaded")
+    }
+
+    // Safe - validates against current VM state.  Returns None unless exactly one VM is loaded.
+    pub fn get_implicit_gen_vm(&self) -> Option<GenVM> {
+        if self.loaded.len() == 1 { Some(self.loaded[0]) } else { None }
+    }
+}
+
+#[test] fn multi_vm_backend_generations() {
+    let a = 0x1000 as *mut JavaVM;
+    let b = 0x2000 as *mut JavaVM;
+
+    let mut backend = MultiVmBackend::new();
+    unsafe { backend.on_load(a) };
+    unsafe { backend.on_load(b) };
+
+    let gen_a = backend.get_gen_vm(a);
+    let gen_b = backend.get_gen_vm(b);
+    assert_ne!(gen_a.gen, gen_b.gen);
+    assert_eq!(gen_a.vm, a as *const _);
+    assert_eq!(gen_b.vm, b as *const _);
+
+    // Reloading the same pointer must not resurrect references from the previous generation.
+    assert_eq!(backend.on_unload(a), gen_a);
+    assert_eq!(backend.try_use_vm(gen_a, |_| ()), None);
+    unsafe { backend.on_load(a) };
+    assert_ne!(backend.get_gen_vm(a), gen_a);
+    assert_eq!(backend.get_gen_vm(b), gen_b);
+}
@@ -21,17 +21,28 @@ impl SingleVmBackend {
         self.current.vm = vm;
     }
 
-    // Safe - only invalidates existing VMs, doesn't actually use 'em or free them.
-    pub fn on_unload(&mut self, vm: *const JavaVM) {
+    // Safe - only invalidates existing VMs, doesn't actually use 'em or free them.  Returns the invalidated VM.
+    pub fn on_unload(&mut self, vm: *const JavaVM) -> GenVM {
         assert_eq!(self.current.vm, vm);
+        let unloaded = self.current;
         self.current.gen += 1;
         self.current.vm = null();
+        unloaded
     }
 
     // Safe - validates against current VM state.
-    pub fn use_vm(&self, vm: GenVM, callback: impl FnOnce(&VM)) {
+    pub fn use_vm<R>(&self, vm: GenVM, callback: impl FnOnce(&VM) -> R) -> R {
         assert_eq!(self.current, vm);
-        callback(unsafe { VM::from_jni_local(&*vm.vm) });
+        callback(unsafe { VM::from_jni_local(&*vm.vm) })
+    }
+
+    // Safe - validates against current VM state.  Returns None if the VM has since been unloaded.
+    pub fn try_use_vm<R>(&self, vm: GenVM, callback: impl FnOnce(&VM) -> R) -> Option<R> {
+        if self.current == vm && !vm.vm.is_null() {
+            Some(callback(unsafe { VM::from_jni_local(&*vm.vm) }))
+        } else {
+            None
+        }
     }
 
     // Safe - validates against current VM state.
@@ -39,4 +50,9 @@ impl SingleVmBackend {
         assert_eq!(self.current.vm, vm);
         self.current
     }
+
+    // Safe - validates against current VM state.  Returns None if no VM is loaded.
+    pub fn get_implicit_gen_vm(&self) -> Option<GenVM> {
+        if self.current.vm.is_null() { None } else { Some(self.current) }
+    }
 }
@@ -1,5 +1,7 @@
 use super::*;
+use std::collections::HashMap;
 use std::os::raw::c_char;
+use std::sync::Mutex;
 
 /// FFI:  Use **&Env** instead of \*const JNIEnv.  This represents a per-thread Java exection environment.
 /// 
@@ -48,6 +50,31 @@ use std::os::raw::c_char;
 #[repr(transparent)]
 pub struct Env(JNIEnv);
 
+/// Classes and method IDs cached by [Env::require_cached_class] etc. for a single [VM] generation.
+///
+/// [Env::require_cached_class]:    struct.Env.html#method.require_cached_class
+/// [VM]:                           struct.VM.html
+#[derive(Default)]
+struct VmCache {
+    classes: HashMap<String, CachedClass>,
+    methods: HashMap<(String, String, String), CachedMethod>, // (class, method, descriptor)
+}
+
+struct CachedClass(jclass); // Global reference
+unsafe impl Send for CachedClass {}
+
+struct CachedMethod(jmethodID);
+unsafe impl Send for CachedMethod {}
+
+lazy_static! { // Mutex::new is not const
+    static ref CACHED : Mutex<HashMap<GenVM, VmCache>> = Mutex::new(HashMap::new());
+}
+
+/// Forgets everything cached for `gen_vm`, which has been unloaded.
+pub(crate) fn forget_vm(gen_vm: GenVM) {
+    CACHED.lock().unwrap().remove(&gen_vm);
+}
+
 impl Env {
     pub unsafe fn from_ptr<'env>(ptr: *const JNIEnv) -> &'env Env { &*(ptr as *const Env) }
 
@@ -55,6 +82,44 @@ impl Env {
     pub(crate) unsafe fn from_jni_local(env: &JNIEnv) -> &Env { &*(env as *const JNIEnv as *const Env) }
     pub(crate) unsafe fn from_jni_void_ref(ptr: &*mut c_void) -> &Env { Self::from_jni_local(&*(*ptr as *const c_void as *const JNIEnv)) }
 
+    /// Get the current thread's [Env] for the one and only loaded [VM], attaching the current thread if necessary.
+    /// Threads attached by this will remain attached until they exit.  This is what bindings generated with
+    /// `static_env = "implicit"` use to call static methods without an explicit [Env] parameter.
+    ///
+    /// Panics if no [VM] is loaded, or (with the "multi-vm" feature) if multiple VMs are loaded.
+    ///
+    /// # Safety
+    ///
+    /// `'env` is unbounded - the caller chooses it.  The returned [Env], and any [Local]s created with it, must not
+    /// outlive the current thread's local frame (e.g. the native method being run) or attachment, and must not be used
+    /// after the [VM] is unloaded.  Bindings generated with `static_env = "implicit"` are `unsafe fn`s for this reason.
+    /// Prefer [with_implicit_vm](#method.with_implicit_vm) where you can.
+    ///
+    /// [Env]:      struct.Env.html
+    /// [Local]:    struct.Local.html
+    /// [VM]:       struct.VM.html
+    pub unsafe fn from_implicit_vm<'env>() -> &'env Env {
+        let gen_vm = VMS.read().unwrap().get_implicit_gen_vm().expect("Env::from_implicit_vm requires exactly one loaded VM");
+        let vm = VM::from_jni_local(&*gen_vm.vm);
+        let attach = vm.attach();
+        let env = attach.env().as_jni_env();
+        attach.detach_on_thread_exit();
+        Env::from_ptr(env)
+    }
+
+    /// Run `callback` with the current thread's [Env] for the one and only loaded [VM], attaching the current thread if
+    /// necessary.  A safe alternative to [from_implicit_vm](#method.from_implicit_vm):  the [Env] and any [Local]s
+    /// created with it can't escape `callback`.
+    ///
+    /// Panics if no [VM] is loaded, or (with the "multi-vm" feature) if multiple VMs are loaded.
+    ///
+    /// [Env]:      struct.Env.html
+    /// [Local]:    struct.Local.html
+    /// [VM]:       struct.VM.html
+    pub fn with_implicit_vm<R>(callback: impl FnOnce(&Env) -> R) -> R {
+        callback(unsafe { Self::from_implicit_vm() })
+    }
+
     pub(crate) fn get_gen_vm(&self) -> GenVM {
         let jni_env = self.as_jni_env();
         let mut vm = null_mut();
@@ -86,6 +151,270 @@ impl Env {
         (**env).ReleaseStringChars.unwrap()(env, string, chars as *const _)
     }
 
+    /// Uses env.GetStringRegion to copy `len` UTF-16 code units, starting at `start`, into `buffer`.
+    ///
+    /// # Safety
+    ///
+    /// `string` must be a valid, non-null, local or global reference to a java.lang.String, with no exception pending.
+    /// `buffer` must be valid for `len` writes.  An out of bounds region throws StringIndexOutOfBoundsException.
+    pub unsafe fn get_string_region(&self, string: jstring, start: jsize, len: jsize, buffer: *mut jchar) {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).GetStringRegion.unwrap()(env, string, start, len, buffer as *mut _)
+    }
+
+    /// Uses env.NewStringUTF to create a java.lang.String from NUL terminated modified UTF-8.  Returns null (with an
+    /// exception pending) on failure.
+    ///
+    /// # Safety
+    ///
+    /// `chars` must point to a NUL terminated, valid [modified UTF-8](fn.encode_modified_utf8.html) string - standard
+    /// UTF-8 containing supplementary characters is rejected or misdecoded by some VMs.  No exception may be pending.
+    pub unsafe fn new_string_utf(&self, chars: *const c_char) -> jstring {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).NewStringUTF.unwrap()(env, chars)
+    }
+
+    /// Uses env.GetStringUTFLength to get the length of `string`'s modified UTF-8 encoding, in bytes.
+    ///
+    /// # Safety
+    ///
+    /// `string` must be a valid, non-null, local or global reference to a java.lang.String, with no exception pending.
+    pub unsafe fn get_string_utf_length(&self, string: jstring) -> jsize {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).GetStringUTFLength.unwrap()(env, string)
+    }
+
+    /// Uses env.GetStringUTFChars to get `string`'s NUL terminated modified UTF-8 encoding, or null on OOM.
+    ///
+    /// # Safety
+    ///
+    /// `string` must be a valid, non-null, local or global reference to a java.lang.String, with no exception pending.
+    /// The result must be passed to [release_string_utf_chars](#method.release_string_utf_chars) with the same string.
+    pub unsafe fn get_string_utf_chars(&self, string: jstring) -> *const c_char {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).GetStringUTFChars.unwrap()(env, string, null_mut())
+    }
+
+    /// Uses env.ReleaseStringUTFChars to free the result of [get_string_utf_chars](#method.get_string_utf_chars).
+    ///
+    /// # Safety
+    ///
+    /// `chars` must have been returned by get_string_utf_chars for `string`, and not already released.  `chars` is
+    /// dangling afterwards.
+    pub unsafe fn release_string_utf_chars(&self, string: jstring, chars: *const c_char) {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).ReleaseStringUTFChars.unwrap()(env, string, chars)
+    }
+
+    /// Uses env.GetStringUTFRegion to copy the modified UTF-8 encoding of `len` UTF-16 code units, starting at `start`,
+    /// into `buffer`.  Note that `start` and `len` are in UTF-16 code units, *not* bytes.
+    ///
+    /// # Safety
+    ///
+    /// `string` must be a valid, non-null, local or global reference to a java.lang.String, with no exception pending.
+    /// `buffer` must be valid for writing the encoded region - up to 3 bytes per code unit - plus a NUL, which some VMs
+    /// append.  An out of bounds region throws StringIndexOutOfBoundsException.
+    pub unsafe fn get_string_utf_region(&self, string: jstring, start: jsize, len: jsize, buffer: *mut c_char) {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).GetStringUTFRegion.unwrap()(env, string, start, len, buffer)
+    }
+
+    /// Uses env.NewStringUTF to create a new java string from `string`, converting to [modified UTF-8] only if
+    /// necessary.  Only sane failure is an OOM.
+    ///
+    /// [modified UTF-8]:   fn.encode_modified_utf8.html
+    pub fn new_string_from_str(&self, string: &str) -> jstring {
+        let mut encoded = encode_modified_utf8(string).into_owned();
+        encoded.push(0);
+        let string = unsafe { self.new_string_utf(encoded.as_ptr() as *const c_char) };
+        assert!(!string.is_null()); // Only sane failure here is an OOM
+        string
+    }
+
+    // Direct Buffer Methods
+
+    /// Uses env.NewDirectByteBuffer to create a java.nio.ByteBuffer wrapping `capacity` bytes at `address`, without
+    /// copying them.  Returns null (with an exception pending) on failure.
+    ///
+    /// # Safety
+    ///
+    /// `address` must point to `capacity` bytes that stay valid, and aren't mutably aliased, for as long as Java may
+    /// access the buffer - which may be long after this returns.  No exception may be pending.
+    pub unsafe fn new_direct_byte_buffer(&self, address: *mut c_void, capacity: jlong) -> jobject {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).NewDirectByteBuffer.unwrap()(env, address, capacity)
+    }
+
+    /// Uses env.GetDirectBufferAddress to get the memory a direct java.nio.Buffer wraps, or null if it isn't direct.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must be a valid, non-null, local or global reference to a java.nio.Buffer, with no exception pending.
+    pub unsafe fn get_direct_buffer_address(&self, buffer: jobject) -> *mut c_void {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).GetDirectBufferAddress.unwrap()(env, buffer)
+    }
+
+    /// Uses env.GetDirectBufferCapacity to get a direct java.nio.Buffer's capacity, or -1 if it isn't direct.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must be a valid, non-null, local or global reference to a java.nio.Buffer, with no exception pending.
+    pub unsafe fn get_direct_buffer_capacity(&self, buffer: jobject) -> jlong {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        (**env).GetDirectBufferCapacity.unwrap()(env, buffer)
+    }
+
+    // Exception Methods
+
+    /// Uses env.Throw to make `throwable` the pending exception, to be thrown when a native method returns to Java.
+    /// While an exception is pending, most JNI calls are forbidden - return to Java promptly.
+    pub fn throw<T: ThrowableType>(&self, throwable: &Ref<'_, T>) {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        let result = unsafe { (**env).Throw.unwrap()(env, throwable.oae.object) };
+        debug_assert_eq!(result, JNI_OK);
+    }
+
+    /// Uses env.ThrowNew to construct a new `T` with `message`, and make it the pending exception, to be thrown when a
+    /// native method returns to Java.  Panics if `T` isn't a java.lang.Throwable.
+    pub fn throw_new<T: JniType>(&self, message: &str) {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        let mut message = encode_modified_utf8(message).into_owned();
+        message.push(0);
+        unsafe {
+            let class = T::static_with_jni_type(|ty| {
+                let mut ty = ty.trim_end_matches('\0').to_owned();
+                ty.push('\0');
+                self.require_class(&ty)
+            });
+            let throwable = self.require_class("java/lang/Throwable\0");
+            assert!((**env).IsAssignableFrom.unwrap()(env, class, throwable) != JNI_FALSE, "throw_new: T must be a java.lang.Throwable");
+            let result = (**env).ThrowNew.unwrap()(env, class, message.as_ptr() as *const c_char);
+            debug_assert_eq!(result, JNI_OK);
+            (**env).DeleteLocalRef.unwrap()(env, throwable);
+            (**env).DeleteLocalRef.unwrap()(env, class);
+        }
+    }
+
+    /// Uses env.ExceptionCheck to determine if there's a pending exception.
+    pub fn exception_check(&self) -> bool {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        unsafe { (**env).ExceptionCheck.unwrap()(env) != JNI_FALSE }
+    }
+
+    /// Uses env.ExceptionDescribe to print the pending exception (if any) and its stack trace to stderr.  This also
+    /// clears the pending exception.  Mostly useful for debugging.
+    pub fn exception_describe(&self) {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        unsafe { (**env).ExceptionDescribe.unwrap()(env) }
+    }
+
+    // Class Methods
+
+    /// Uses env.DefineClass to define the class `name` (e.g. "com/example/Foo") from the contents of a .class file,
+    /// using `loader`, or the bootstrap class loader if `None`.  `Class` should be your bindings' java.lang.Class.
+    ///
+    /// Android doesn't support DefineClass, and will always return an Err here.  See [include_class!] for embedding
+    /// classes in a way that also works there.
+    ///
+    /// [include_class!]:   macro.include_class.html
+    pub fn define_class<'env, Class: AsValidJObjectAndEnv, Loader: AsValidJObjectAndEnv, E: ThrowableType>(&'env self, name: &str, loader: Option<&Loader>, bytes: &[u8]) -> Result<Local<'env, Class>, Local<'env, E>> {
+        debug_assert!(Class::static_with_jni_type(|ty| ty.trim_end_matches('\0') == "java/lang/Class"), "define_class: Class must be java.lang.Class");
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        let mut name = encode_modified_utf8(name).into_owned();
+        name.push(0);
+        unsafe {
+            let class = (**env).DefineClass.unwrap()(env, name.as_ptr() as *const c_char, loader.as_jvalue().l, bytes.as_ptr() as *const jbyte, bytes.len() as jsize);
+            if class.is_null() {
+                if !self.exception_check() { // Android returns null without throwing
+                    let unsupported = self.require_class("java/lang/UnsupportedOperationException\0");
+                    (**env).ThrowNew.unwrap()(env, unsupported, "DefineClass isn't supported by this VM\0".as_ptr() as *const c_char);
+                    (**env).DeleteLocalRef.unwrap()(env, unsupported);
+                }
+                return Err(take_exception(self));
+            }
+            Ok(Local::from_env_object(env, class))
+        }
+    }
+
+    /// Uses env.IsInstanceOf to determine if `object` is an instance of `class` (e.g. "java/io/IOException\0".)  Like
+    /// IsInstanceOf, null is considered an instance of every class.  `class` is only looked up once per [VM].
+    ///
+    /// # Safety
+    ///
+    /// `object` must be null or a valid local or global reference, with no exception pending.  `class` must be NUL
+    /// terminated.  Panics if `class` can't be found.
+    ///
+    /// [VM]:   struct.VM.html
+    pub unsafe fn is_instance_of(&self, object: jobject, class: &str) -> bool {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        let class = self.require_cached_class(class);
+        (**env).IsInstanceOf.unwrap()(env, object, class) != JNI_FALSE
+    }
+
+    /// Like require_class, but returns a global reference, cached for the lifetime of the [VM].  Don't delete it!
+    ///
+    /// # Safety
+    ///
+    /// No exception may be pending.  `class` must be NUL terminated.  Panics if `class` can't be found.
+    ///
+    /// [VM]:   struct.VM.html
+    pub unsafe fn require_cached_class(&self, class: &str) -> jclass {
+        let env = &self.0 as *const JNIEnv as *mut JNIEnv;
+        let gen_vm = self.get_gen_vm();
+        if let Some(class) = CACHED.lock().unwrap().get(&gen_vm).and_then(|cache| cache.classes.get(class)) { return class.0; }
+
+        // Not locked while finding the class, as FindClass may run static initializers which reenter this.
+        let local = self.require_class(class);
+        let global = (**env).NewGlobalRef.unwrap()(env, local);
+        assert!(!global.is_null()); // Only sane failure here is an OOM
+        (**env).DeleteLocalRef.unwrap()(env, local);
+
+        let mut cached = CACHED.lock().unwrap();
+        let cached = cached.entry(gen_vm).or_default().classes.entry(class.to_owned()).or_insert(CachedClass(global));
+        if cached.0 != global { (**env).DeleteGlobalRef.unwrap()(env, global); } // Another thread beat us to it
+        cached.0
+    }
+
+    /// Like require_class_method, but the class (a global reference - don't delete it!) and method ID are cached for
+    /// the lifetime of the [VM].
+    ///
+    /// # Safety
+    ///
+    /// No exception may be pending.  `class`, `method` and `descriptor` must be NUL terminated.  Panics if the class or
+    /// method can't be found.
+    ///
+    /// [VM]:   struct.VM.html
+    pub unsafe fn require_cached_class_method(&self, class: &str, method: &str, descriptor: &str) -> (jclass, jmethodID) {
+        self.require_cached_method_impl(class, method, descriptor, |class| self.require_method(class, method, descriptor))
+    }
+
+    /// Like require_class_static_method, but the class (a global reference - don't delete it!) and method ID are cached
+    /// for the lifetime of the [VM].
+    ///
+    /// # Safety
+    ///
+    /// No exception may be pending.  `class`, `method` and `descriptor` must be NUL terminated.  Panics if the class or
+    /// static method can't be found.
+    ///
+    /// [VM]:   struct.VM.html
+    pub unsafe fn require_cached_class_static_method(&self, class: &str, method: &str, descriptor: &str) -> (jclass, jmethodID) {
+        // N.B. a class can't have both a static and an instance method with the same name and descriptor.
+        self.require_cached_method_impl(class, method, descriptor, |class| self.require_static_method(class, method, descriptor))
+    }
+
+    unsafe fn require_cached_method_impl(&self, class: &str, method: &str, descriptor: &str, require: impl FnOnce(jclass) -> jmethodID) -> (jclass, jmethodID) {
+        let gen_vm = self.get_gen_vm();
+        let key = (class.to_owned(), method.to_owned(), descriptor.to_owned());
+        let jclass = self.require_cached_class(class);
+        if let Some(method) = CACHED.lock().unwrap().get(&gen_vm).and_then(|cache| cache.methods.get(&key)) { return (jclass, method.0); }
+
+        // Method IDs are stable for the lifetime of the class, so it doesn't matter which thread wins any race.
+        let method = require(jclass);
+        CACHED.lock().unwrap().entry(gen_vm).or_default().methods.insert(key, CachedMethod(method));
+        (jclass, method)
+    }
+
     // Query Methods
 
     pub unsafe fn require_class(&self, class: &str) -> jclass {
@@ -633,3 +962,80 @@ impl Env {
         (**env).SetStaticDoubleField.unwrap()(env, class, field, value);
     }
 }
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn env_direct_byte_buffer() {
+    // Direct buffers should round trip the exact memory they were created with.
+    with_test_env(|env|{
+        let mut data = [1u8, 2, 3, 4];
+        let buffer = unsafe { env.new_direct_byte_buffer(data.as_mut_ptr() as *mut c_void, data.len() as jlong) };
+        assert!(!buffer.is_null());
+        assert_eq!(unsafe { env.get_direct_buffer_address(buffer) }, data.as_mut_ptr() as *mut c_void);
+        assert_eq!(unsafe { env.get_direct_buffer_capacity(buffer) }, 4);
+        let object : Local<TestThrowable> = new_test_object(env, "java/lang/Throwable\0");
+        assert_eq!(unsafe { env.get_direct_buffer_address(object.oae.object) }, null_mut()); // Not a buffer
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn env_modified_utf8_strings() {
+    // Strings with NULs and supplementary characters should survive the trip through modified UTF-8.
+    with_test_env(|env|{
+        for text in ["ascii", "nul\0nul", "\u{00E9}\u{4E2D}\u{1F600}"].iter() {
+            let string = env.new_string_from_str(text);
+            let utf16 = text.encode_utf16().collect::<Vec<_>>();
+            let mut region = vec![jchar::default(); utf16.len()];
+            unsafe { env.get_string_region(string, 0, utf16.len() as jsize, region.as_mut_ptr()) };
+            assert_eq!(region.iter().map(|ch| ch.0).collect::<Vec<_>>(), utf16);
+
+            let utf_len = unsafe { env.get_string_utf_length(string) } as usize;
+            let chars = unsafe { env.get_string_utf_chars(string) };
+            let bytes = unsafe { std::slice::from_raw_parts(chars as *const u8, utf_len) };
+            assert_eq!(decode_modified_utf8(bytes).unwrap(), *text);
+            unsafe { env.release_string_utf_chars(string, chars) };
+
+            let mut region = vec![0 as c_char; utf_len + 1]; // HotSpot NUL terminates
+            unsafe { env.get_string_utf_region(string, 0, utf16.len() as jsize, region.as_mut_ptr()) };
+            assert_eq!(region[..utf_len].iter().map(|b| *b as u8).collect::<Vec<_>>(), &*encode_modified_utf8(text));
+        }
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn env_define_class_failure() {
+    // Malformed classes should be returned as errors, rather than left pending.
+    with_test_env(|env|{
+        let error = env.define_class::<TestClass, TestClass, TestThrowable>("com/example/Empty", None, &[]).err().unwrap();
+        assert_eq!(JavaException::from(error).class_name(), "java.lang.ClassFormatError");
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn env_require_cached_class_method() {
+    // Cached lookups should hand back the same global class and method IDs every time.
+    with_test_env(|env| unsafe {
+        let (class, value_of) = env.require_cached_class_static_method("java/lang/Integer\0", "valueOf\0", "(I)Ljava/lang/Integer;\0");
+        let (class2, int_value) = env.require_cached_class_method("java/lang/Integer\0", "intValue\0", "()I\0");
+        assert_eq!(class, class2);
+        assert_eq!(class, env.require_cached_class("java/lang/Integer\0"));
+        assert_eq!(env.require_cached_class_static_method("java/lang/Integer\0", "valueOf\0", "(I)Ljava/lang/Integer;\0"), (class, value_of));
+        assert_eq!(env.require_cached_class_method("java/lang/Integer\0", "intValue\0", "()I\0"), (class, int_value));
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn env_with_implicit_vm() {
+    // Threads that aren't attached yet should be able to reach the one and only VM without an Env.
+    test_vm();
+    std::thread::spawn(|| Env::with_implicit_vm(|env| unsafe {
+        let string = env.new_string_from_str("implicit");
+        assert_eq!(env.get_string_length(string), 8);
+    })).join().unwrap();
+}
+
+#[test] fn env_forget_vm() {
+    // Unloading a VM should forget only what was cached for that VM.
+    let unloaded = GenVM { gen: usize::MAX,     vm: null() };
+    let loaded   = GenVM { gen: usize::MAX - 1, vm: null() };
+    CACHED.lock().unwrap().insert(unloaded, VmCache::default());
+    CACHED.lock().unwrap().insert(loaded,   VmCache::default());
+    forget_vm(unloaded);
+    assert!(!CACHED.lock().unwrap().contains_key(&unloaded));
+    assert!( CACHED.lock().unwrap().contains_key(&loaded));
+    forget_vm(loaded);
+}
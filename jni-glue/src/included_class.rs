@@ -0,0 +1,238 @@
+use super::*;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+
+
+/// A Java class embedded in your binary by [include_class!], and defined on first use - once per [VM] generation.
+///
+/// Classes are found with env.FindClass if possible, defined with [Env::define_class] (using the bootstrap class
+/// loader) if not, and - if a dex file was embedded too - loaded with a `dalvik.system.InMemoryDexClassLoader` if that
+/// fails.  The latter requires Android 8.0 (API level 26) or later, and uses the current thread's context class loader
+/// as its parent - on Android, that's the app's class loader for threads created by Java.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # #[repr(transparent)] pub struct Class(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for Class {}
+/// # unsafe impl AsJValue for Class { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for Class { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Class\0") } }
+/// # #[repr(transparent)] pub struct Throwable(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for Throwable {}
+/// # unsafe impl AsJValue for Throwable { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for Throwable { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Throwable\0") } }
+/// # impl ThrowableType for Throwable {}
+/// static HANDLER : IncludedClass = jni_glue::include_class!("com/maulingmonkey/jni_glue/RustInvocationHandler", "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class");
+///
+/// fn example<'env>(env: &'env Env) -> Result<(), Local<'env, Throwable>> {
+///     let class : Local<Class> = HANDLER.get(env)?;
+///     // ...
+///     Ok(())
+/// }
+/// ```
+///
+/// [include_class!]:       macro.include_class.html
+/// [VM]:                   struct.VM.html
+/// [Env::define_class]:    struct.Env.html#method.define_class
+pub struct IncludedClass {
+    name:   &'static str,
+    class:  &'static [u8],
+    dex:    Option<&'static [u8]>,
+}
+
+/// Embeds a .class file (and optionally a .dex file containing the same class, for Android) as an [IncludedClass].
+/// Paths are relative to the current file, as with `include_bytes!`.
+///
+/// ```rust,no_run
+/// # use jni_glue::IncludedClass;
+/// static HANDLER : IncludedClass = jni_glue::include_class!("com/maulingmonkey/jni_glue/RustInvocationHandler", "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class");
+///
+/// // For Android versions where defining classes from .class files isn't supported, also embed a .dex:
+/// // static HELPER : IncludedClass = jni_glue::include_class!("com/example/Helper", "Helper.class", dex: "helper.dex");
+/// ```
+///
+/// [IncludedClass]:    struct.IncludedClass.html
+#[macro_export] macro_rules! include_class {
+    ( $name:literal, $class:literal $(,)? ) => {
+        $crate::IncludedClass::__new(concat!($name, "\0"), include_bytes!($class), None)
+    };
+    ( $name:literal, $class:literal, dex: $dex:literal $(,)? ) => {
+        $crate::IncludedClass::__new(concat!($name, "\0"), include_bytes!($class), Some(include_bytes!($dex) as &[u8]))
+    };
+}
+
+struct DefinedClass(jobject); // Global reference
+unsafe impl Send for DefinedClass {}
+
+lazy_static! { // Mutex::new is not const
+    static ref DEFINED : Mutex<HashMap<(GenVM, usize), DefinedClass>> = Mutex::new(HashMap::new());
+}
+
+/// Forgets the classes defined for `gen_vm`, which has been unloaded.
+pub(crate) fn forget_vm(gen_vm: GenVM) {
+    DEFINED.lock().unwrap().retain(|&(defined_vm, _), _| defined_vm != gen_vm);
+}
+
+impl IncludedClass {
+    #[doc(hidden)] // Use include_class! instead
+    pub const fn __new(name: &'static str, class: &'static [u8], dex: Option<&'static [u8]>) -> Self {
+        Self { name, class, dex }
+    }
+
+    /// The class name, e.g. "com/example/Helper".
+    pub fn name(&self) -> &'static str { self.name.trim_end_matches('\0') }
+
+    /// Gets the class, defining it if it hasn't already been for `env`'s [VM].  `Class` should be your bindings'
+    /// java.lang.Class.
+    ///
+    /// [VM]:   struct.VM.html
+    pub fn get<'env, Class: AsValidJObjectAndEnv, E: ThrowableType>(&'static self, env: &'env Env) -> Result<Local<'env, Class>, Local<'env, E>> {
+        let jnienv = env.as_jni_env();
+        let key = (env.get_gen_vm(), self as *const Self as usize);
+        if let Some(class) = DEFINED.lock().unwrap().get(&key) {
+            return Ok(unsafe { Local::from_env_object(jnienv, (**jnienv).NewLocalRef.unwrap()(jnienv, class.0)) });
+        }
+
+        // Not locked while defining, as FindClass may run static initializers which reenter this.
+        let class = unsafe { self.define::<Class, E>(env)? };
+        let global = unsafe { (**jnienv).NewGlobalRef.unwrap()(jnienv, class.oae.object) };
+        assert!(!global.is_null()); // Only sane failure here is an OOM
+
+        let mut defined = DEFINED.lock().unwrap();
+        let defined = defined.entry(key).or_insert(DefinedClass(global));
+        if defined.0 == global { return Ok(class); }
+
+        // Another thread beat us to it.  Prefer their class, in case we loaded a second copy (e.g. from dex.)
+        unsafe { (**jnienv).DeleteGlobalRef.unwrap()(jnienv, global) };
+        Ok(unsafe { Local::from_env_object(jnienv, (**jnienv).NewLocalRef.unwrap()(jnienv, defined.0)) })
+    }
+
+    unsafe fn define<'env, Class: AsValidJObjectAndEnv, E: ThrowableType>(&self, env: &'env Env) -> Result<Local<'env, Class>, Local<'env, E>> {
+        if let Some(class) = self.find(env) { return Ok(class); }
+
+        match (env.define_class::<Class, Class, E>(self.name(), None, self.class), self.dex) {
+            (Ok(class), _) => Ok(class),
+            (Err(err), dex) => {
+                // If another thread defined the class first, we'll have gotten a LinkageError - but can find theirs.
+                if let Some(class) = self.find(env) { return Ok(class); }
+                match dex {
+                    None        => Err(err),
+                    Some(dex)   => { drop(err); self.load_dex(env, dex) },
+                }
+            },
+        }
+    }
+
+    /// env.FindClass, clearing the NoClassDefFoundError if the class couldn't be found.
+    unsafe fn find<'env, Class: AsValidJObjectAndEnv>(&self, env: &'env Env) -> Option<Local<'env, Class>> {
+        let jnienv = env.as_jni_env();
+        let class = (**jnienv).FindClass.unwrap()(jnienv, self.name.as_ptr() as *const c_char);
+        if class.is_null() {
+            (**jnienv).ExceptionClear.unwrap()(jnienv); // NoClassDefFoundError
+            None
+        } else {
+            Some(Local::from_env_object(jnienv, class))
+        }
+    }
+
+    unsafe fn load_dex<'env, Class: AsValidJObjectAndEnv, E: ThrowableType>(&self, env: &'env Env, dex: &'static [u8]) -> Result<Local<'env, Class>, Local<'env, E>> {
+        let jnienv = env.as_jni_env();
+        let mut locals = Vec::new();
+        let class = self.load_dex_impl(env, dex, &mut locals);
+        for local in locals.into_iter().rev() {
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, local);
+        }
+        match class {
+            Some(class) => Ok(Local::from_env_object(jnienv, class)),
+            None        => Err(take_exception(env)),
+        }
+    }
+
+    /// Returns None with an exception pending on failure.  Every local reference created, other than the class itself,
+    /// is pushed to `locals` for the caller to delete.
+    unsafe fn load_dex_impl(&self, env: &Env, dex: &'static [u8], locals: &mut Vec<jobject>) -> Option<jclass> {
+        let jnienv = env.as_jni_env();
+        let mut local = |object: jobject| { if !object.is_null() { locals.push(object); } object };
+
+        let loader_class = local((**jnienv).FindClass.unwrap()(jnienv, "dalvik/system/InMemoryDexClassLoader\0".as_ptr() as *const c_char));
+        if loader_class.is_null() { return None; }
+        let loader_ctor = env.require_method(loader_class, "<init>\0", "(Ljava/nio/ByteBuffer;Ljava/lang/ClassLoader;)V\0");
+
+        let (thread_class, current_thread) = env.require_class_static_method("java/lang/Thread\0", "currentThread\0", "()Ljava/lang/Thread;\0");
+        local(thread_class);
+        let get_context_class_loader = env.require_method(thread_class, "getContextClassLoader\0", "()Ljava/lang/ClassLoader;\0");
+        let thread = local((**jnienv).CallStaticObjectMethodA.unwrap()(jnienv, thread_class, current_thread, null()));
+        if env.exception_check() { return None; }
+        let parent = local((**jnienv).CallObjectMethodA.unwrap()(jnienv, thread, get_context_class_loader, null())); // May be null (bootstrap)
+        if env.exception_check() { return None; }
+
+        let buffer = local(env.new_direct_byte_buffer(dex.as_ptr() as *mut c_void, dex.len() as jlong)); // Only read from
+        if buffer.is_null() {
+            if !env.exception_check() { env.throw_new::<UnsupportedOperationException>("JNI NewDirectByteBuffer isn't supported by this VM"); }
+            return None;
+        }
+
+        let args = [jvalue { l: buffer }, jvalue { l: parent }];
+        let loader = local((**jnienv).NewObjectA.unwrap()(jnienv, loader_class, loader_ctor, args.as_ptr()));
+        if loader.is_null() { return None; }
+
+        let (class_loader_class, load_class) = env.require_class_method("java/lang/ClassLoader\0", "loadClass\0", "(Ljava/lang/String;)Ljava/lang/Class;\0");
+        local(class_loader_class);
+        let binary_name = local(env.new_string_from_str(&self.name().replace('/', ".")));
+        let args = [jvalue { l: binary_name }];
+        let class = (**jnienv).CallObjectMethodA.unwrap()(jnienv, loader, load_class, args.as_ptr());
+        if env.exception_check() { return None; }
+        Some(class)
+    }
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn included_class_get() {
+    // Embedded classes should be defined once per VM, with failures returned rather than left pending.
+    static HANDLER      : IncludedClass = crate::include_class!("com/maulingmonkey/jni_glue/RustInvocationHandler", "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class");
+    static MISNAMED     : IncludedClass = crate::include_class!("com/example/Misnamed", "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class");
+    static NOT_DALVIK   : IncludedClass = crate::include_class!("com/example/NotDalvik", "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class", dex: "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class");
+    with_test_env(|env|{
+        let first  = HANDLER.get::<TestClass, TestThrowable>(env).unwrap();
+        let second = HANDLER.get::<TestClass, TestThrowable>(env).unwrap();
+        assert!(unsafe { (**env.as_jni_env()).IsSameObject.unwrap()(env.as_jni_env(), first.oae.object, second.oae.object) } != JNI_FALSE);
+        assert_eq!(JavaException::from(MISNAMED.get::<TestClass, TestThrowable>(env).err().unwrap()).class_name(), "java.lang.NoClassDefFoundError");
+        assert_eq!(JavaException::from(NOT_DALVIK.get::<TestClass, TestThrowable>(env).err().unwrap()).class_name(), "java.lang.NoClassDefFoundError");
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn included_class_get_racing() {
+    // Threads racing to define the same class should all end up with the same one, rather than errors.
+    const RACY_BYTES : &[u8] = &[
+        0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x00, 0x00, 0x34,
+        0x00, 0x05, // 4 constants
+        0x01, 0x00, 0x12, b'c', b'o', b'm', b'/', b'e', b'x', b'a', b'm', b'p', b'l', b'e', b'/', b'R', b'a', b'c', b'y', b'0', b'1',
+        0x07, 0x00, 0x01,                                                               // #2:  Class #1
+        0x01, 0x00, 0x10, b'j', b'a', b'v', b'a', b'/', b'l', b'a', b'n', b'g', b'/', b'O', b'b', b'j', b'e', b'c', b't',
+        0x07, 0x00, 0x03,                                                               // #4:  Class #3
+        0x00, 0x21, 0x00, 0x02, 0x00, 0x04,                                             // access, this, super
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,                                 // interfaces, fields, methods, attributes
+    ];
+    static RACY : IncludedClass = IncludedClass::__new("com/example/Racy01\0", RACY_BYTES, None);
+
+    let vm = test_vm();
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(8));
+    let threads = (0..8).map(|_| {
+        let barrier = barrier.clone();
+        std::thread::spawn(move || vm.with_env(|env|{
+            barrier.wait();
+            let class = RACY.get::<TestClass, TestThrowable>(env).unwrap();
+            unsafe { (**env.as_jni_env()).NewGlobalRef.unwrap()(env.as_jni_env(), class.oae.object) as usize }
+        }))
+    }).collect::<Vec<_>>();
+    let classes = threads.into_iter().map(|t| t.join().unwrap() as jobject).collect::<Vec<_>>();
+    with_test_env(|env| unsafe {
+        let jnienv = env.as_jni_env();
+        for class in classes.iter() {
+            assert!((**jnienv).IsSameObject.unwrap()(jnienv, classes[0], *class) != JNI_FALSE);
+        }
+        for class in classes.iter() {
+            (**jnienv).DeleteGlobalRef.unwrap()(jnienv, *class);
+        }
+    });
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+
+
+/// Types that can be passed as a java.lang.String argument by bindings generated with
+/// `string_params = "into_java_string"`.  `S` is the bindings' java.lang.String type.
+///
+/// | Rust Type                     | Conversion                                                        |
+/// | ----------------------------- | ----------------------------------------------------------------- |
+/// | &[str], &[String]             | A temporary string via env.NewStringUTF, deleted after the call   |
+/// | &[JavaStr], &[JavaString]     | A temporary string via env.NewString, deleted after the call      |
+/// | &S, Option<&S>, None          | Passed as-is                                                      |
+///
+/// [str]:          https://doc.rust-lang.org/std/primitive.str.html
+/// [String]:       https://doc.rust-lang.org/std/string/struct.String.html
+/// [JavaStr]:      struct.JavaStr.html
+/// [JavaString]:   struct.JavaString.html
+pub trait IntoJavaString<'env, S: AsValidJObjectAndEnv> {
+    fn into_java_string(self, env: &'env Env) -> JavaStringArg<'env, S>;
+}
+
+/// A java.lang.String argument, which may be a temporary [Local] that will be deleted when dropped.
+///
+/// [Local]:    struct.Local.html
+pub enum JavaStringArg<'env, S: AsValidJObjectAndEnv> {
+    Ref(Option<&'env S>),
+    Local(Local<'env, S>),
+}
+
+impl<'env, S: AsValidJObjectAndEnv> JavaStringArg<'env, S> {
+    unsafe fn from_env_jstring(env: &'env Env, string: jstring) -> Self {
+        S::static_with_jni_type(|ty| assert_eq!(ty.trim_end_matches('\0'), "java/lang/String")); // Soundness check
+        assert!(!string.is_null()); // Only sane failure here is an OOM
+        JavaStringArg::Local(Local::from_env_object(env.as_jni_env(), string))
+    }
+}
+
+unsafe impl<'env, S: AsValidJObjectAndEnv> AsJValue for JavaStringArg<'env, S> {
+    fn as_jvalue(&self) -> jvalue {
+        match self {
+            JavaStringArg::Ref(r)       => r.as_jvalue(),
+            JavaStringArg::Local(local) => (**local).as_jvalue(),
+        }
+    }
+}
+
+impl<'env, S: AsValidJObjectAndEnv> IntoJavaString<'env, S> for &str {
+    fn into_java_string(self, env: &'env Env) -> JavaStringArg<'env, S> {
+        unsafe { JavaStringArg::from_env_jstring(env, env.new_string_from_str(self)) }
+    }
+}
+
+impl<'env, S: AsValidJObjectAndEnv> IntoJavaString<'env, S> for &String {
+    fn into_java_string(self, env: &'env Env) -> JavaStringArg<'env, S> { self.as_str().into_java_string(env) }
+}
+
+impl<'env, S: AsValidJObjectAndEnv> IntoJavaString<'env, S> for &JavaStr {
+    fn into_java_string(self, env: &'env Env) -> JavaStringArg<'env, S> {
+        let chars = self.to_jchars();
+        unsafe { JavaStringArg::from_env_jstring(env, env.new_string(chars.as_ptr(), chars.len() as jsize)) }
+    }
+}
+
+impl<'env, S: AsValidJObjectAndEnv> IntoJavaString<'env, S> for &JavaString {
+    fn into_java_string(self, env: &'env Env) -> JavaStringArg<'env, S> { (&**self).into_java_string(env) }
+}
+
+impl<'env, S: AsValidJObjectAndEnv> IntoJavaString<'env, S> for &'env S {
+    fn into_java_string(self, _env: &'env Env) -> JavaStringArg<'env, S> { JavaStringArg::Ref(Some(self)) }
+}
+
+impl<'env, S: AsValidJObjectAndEnv> IntoJavaString<'env, S> for Option<&'env S> {
+    fn into_java_string(self, _env: &'env Env) -> JavaStringArg<'env, S> { JavaStringArg::Ref(self) }
+}
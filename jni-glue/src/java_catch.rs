@@ -0,0 +1,87 @@
+use super::*;
+
+
+
+impl<'env, E: ThrowableType> Local<'env, E> {
+    /// Returns true if this exception is a `T` (e.g. a java.io.FileNotFoundException, or any subclass thereof.)
+    /// Uses env.IsInstanceOf, with `T`'s class looked up once per [VM].
+    ///
+    /// [VM]:   struct.VM.html
+    pub fn is<T: AsValidJObjectAndEnv>(&self) -> bool {
+        self.is_instance_of::<T>()
+    }
+
+    /// Converts this exception into a `Local<T>` if it [is](#method.is) a `T`, or gives it back unchanged if it isn't.
+    pub fn downcast<T: AsValidJObjectAndEnv>(self) -> Result<Local<'env, T>, Self> {
+        self.try_cast()
+    }
+}
+
+/// Matches a thrown exception against several exception types, in order, like a Java `try { ... } catch` chain.  The
+/// first arm whose type the exception [is] an instance of gets it [downcast] to that type, and the final, untyped arm
+/// gets it unchanged if none match.  Any expression evaluating to a `Local<ThrowableType>` may be matched.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # macro_rules! java_class { ( $name:ident, $path:literal ) => {
+/// #     #[repr(transparent)] pub struct $name(ObjectAndEnv);
+/// #     unsafe impl AsValidJObjectAndEnv for $name {}
+/// #     unsafe impl AsJValue for $name { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// #     unsafe impl JniType for $name { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback($path) } }
+/// # }}
+/// # java_class!(Throwable,              "java/lang/Throwable\0");
+/// # java_class!(IOException,            "java/io/IOException\0");
+/// # java_class!(FileNotFoundException,  "java/io/FileNotFoundException\0");
+/// # java_class!(SocketTimeoutException, "java/net/SocketTimeoutException\0");
+/// # impl ThrowableType for Throwable {}
+/// # fn read_file<'env>(env: &'env Env, path: &str) -> Result<Option<String>, Local<'env, Throwable>> { unimplemented!() }
+/// # fn retry<'env>(env: &'env Env, e: Local<'env, SocketTimeoutException>) -> Result<Option<String>, Local<'env, Throwable>> { unimplemented!() }
+/// # fn log(e: Local<IOException>) {}
+/// # fn example<'env>(env: &'env Env, path: &str) -> Result<Option<String>, Local<'env, Throwable>> {
+/// let text = match read_file(env, path) {
+///     Ok(text) => text,
+///     Err(e) => jni_glue::java_catch!(e, {
+///         _: FileNotFoundException    => None,
+///         e: SocketTimeoutException   => return retry(env, e),
+///         e: IOException              => { log(e); None },
+///         e                           => return Err(e),
+///     }),
+/// };
+/// # Ok(text)
+/// # }
+/// ```
+///
+/// [is]:       struct.Local.html#method.is
+/// [downcast]: struct.Local.html#method.downcast
+#[macro_export] macro_rules! java_catch {
+    ( $throwable:expr, { $($arms:tt)* } ) => {{
+        let __jni_throwable = $throwable;
+        $crate::java_catch!(@arms __jni_throwable; $($arms)*)
+    }};
+    ( @arms $throwable:ident; $binding:tt : $ty:ty => $body:expr, $($rest:tt)* ) => {
+        match $crate::Local::downcast::<$ty>($throwable) {
+            Ok($binding)    => $body,
+            Err($throwable) => $crate::java_catch!(@arms $throwable; $($rest)*),
+        }
+    };
+    ( @arms $throwable:ident; $binding:tt => $body:expr $(,)? ) => {{
+        let $binding = $throwable;
+        $body
+    }};
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn java_catch_by_class() {
+    // Exceptions should be matchable by class, falling through to the untyped arm.
+    with_test_env(|env|{
+        let error : Local<TestThrowable> = new_test_object(env, "java/lang/IllegalStateException\0");
+        let error = error.try_cast::<TestClass>().err().unwrap(); // Not a java.lang.Class
+        let error = error.try_cast::<TestThrowable>().ok().unwrap();
+        assert!(!error.is::<TestClass>() && error.is::<TestThrowable>());
+        let error = crate::java_catch!(error, {
+            _: TestClass        => unreachable!(),
+            e: TestThrowable    => e,
+            _                   => unreachable!(),
+        });
+        assert_eq!(JavaException::from(error).class_name(), "java.lang.IllegalStateException");
+    });
+}
@@ -0,0 +1,211 @@
+use super::*;
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::Deref;
+
+
+
+/// An owned Java exception, which - unlike a [Local] - can outlive the [Env], be sent between threads, and be used as a
+/// [std::error::Error] (e.g. with `?` or in an `anyhow::Error`.)
+///
+/// The class name, message, stack trace, and cause chain are eagerly rendered into Rust strings when converted from a
+/// [Local], so they can be displayed later without an [Env].  A [Global] reference to the original exception is also
+/// kept, in case you want to rethrow or otherwise inspect it.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # fn example<Throwable: ThrowableType>(env: &Env, result: Result<(), Local<Throwable>>) -> Result<(), JavaException<Throwable>> {
+/// result?; // Local<Throwable> => JavaException<Throwable>
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Local]:                struct.Local.html
+/// [Env]:                  struct.Env.html
+/// [Global]:               struct.Global.html
+/// [std::error::Error]:    https://doc.rust-lang.org/std/error/trait.Error.html
+pub struct JavaException<T: ThrowableType> {
+    throwable:  Global<T>,
+    details:    ThrowableDetails,
+}
+
+/// The eagerly rendered details of a Java exception (or one of its causes.)
+#[derive(Clone)]
+pub struct ThrowableDetails {
+    class_name:     String,
+    message:        Option<String>,
+    stack_trace:    Vec<String>,
+    cause:          Option<Box<ThrowableDetails>>,
+}
+
+impl<T: ThrowableType> JavaException<T> {
+    /// The original exception.
+    pub fn throwable(&self) -> &Global<T> { &self.throwable }
+
+    /// The rendered details of the original exception.
+    pub fn details(&self) -> &ThrowableDetails { &self.details }
+}
+
+impl ThrowableDetails {
+    /// Renders the details of `throwable`, without taking ownership of it like converting to a [JavaException] does.
+    ///
+    /// [JavaException]:    struct.JavaException.html
+    pub fn of<T: ThrowableType>(throwable: &T) -> Self {
+        unsafe {
+            // AsValidJObjectAndEnv guarantees T is a #[repr(transparent)] wrapper around a valid ObjectAndEnv.
+            let oae = &*(throwable as *const T as *const ObjectAndEnv);
+            Self::capture(Env::from_ptr(oae.env), oae.object, 0)
+        }
+    }
+
+    /// The exception's class name, as returned by getClass().getName() - e.g. `"java.lang.IllegalStateException"`.
+    pub fn class_name(&self) -> &str { &self.class_name }
+
+    /// The exception's message, as returned by getMessage(), or None if it returned null (or threw.)
+    pub fn message(&self) -> Option<&str> { self.message.as_deref() }
+
+    /// The exception's stack frames, as rendered by StackTraceElement.toString() - e.g. `"com.example.Foo.bar(Foo.java:42)"`.
+    pub fn stack_trace(&self) -> &[String] { &self.stack_trace[..] }
+
+    /// The exception's cause, as returned by getCause(), or None if it returned null (or threw.)
+    pub fn cause(&self) -> Option<&ThrowableDetails> { self.cause.as_deref() }
+
+    unsafe fn capture(env: &Env, throwable: jobject, depth: usize) -> Self {
+        const MAX_DEPTH : usize = 32; // Guard against pathological (or cyclic!) cause chains.
+        let jnienv = env.as_jni_env();
+
+        let class = (**jnienv).GetObjectClass.unwrap()(jnienv, throwable);
+        let class_name = call_string_method(env, class, "java/lang/Class\0", "getName\0").unwrap_or_else(|| "N/A".to_owned());
+        (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+
+        let message = call_string_method(env, throwable, "java/lang/Throwable\0", "getMessage\0");
+
+        let mut stack_trace = Vec::new();
+        let (throwable_class, get_stack_trace) = env.require_class_method("java/lang/Throwable\0", "getStackTrace\0", "()[Ljava/lang/StackTraceElement;\0");
+        (**jnienv).DeleteLocalRef.unwrap()(jnienv, throwable_class);
+        let frames = (**jnienv).CallObjectMethodA.unwrap()(jnienv, throwable, get_stack_trace, null());
+        if !clear_exception(env) && !frames.is_null() {
+            for i in 0..(**jnienv).GetArrayLength.unwrap()(jnienv, frames) {
+                let frame = (**jnienv).GetObjectArrayElement.unwrap()(jnienv, frames, i);
+                if clear_exception(env) { break; }
+                if frame.is_null() { stack_trace.push("N/A (frame was null)".to_owned()); continue; }
+                stack_trace.push(call_string_method(env, frame, "java/lang/Object\0", "toString\0").unwrap_or_else(|| "N/A".to_owned()));
+                (**jnienv).DeleteLocalRef.unwrap()(jnienv, frame);
+            }
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, frames);
+        }
+
+        let (throwable_class, get_cause) = env.require_class_method("java/lang/Throwable\0", "getCause\0", "()Ljava/lang/Throwable;\0");
+        (**jnienv).DeleteLocalRef.unwrap()(jnienv, throwable_class);
+        let cause = (**jnienv).CallObjectMethodA.unwrap()(jnienv, throwable, get_cause, null());
+        let cause = if clear_exception(env) || cause.is_null() {
+            None
+        } else {
+            let same = (**jnienv).IsSameObject.unwrap()(jnienv, throwable, cause) != JNI_FALSE;
+            let details = if same || depth >= MAX_DEPTH { None } else { Some(Box::new(Self::capture(env, cause, depth + 1))) };
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, cause);
+            details
+        };
+
+        Self { class_name, message, stack_trace, cause }
+    }
+}
+
+/// Calls a `()Ljava/lang/String;` method on `object`, returning None if it returns null or throws.
+unsafe fn call_string_method(env: &Env, object: jobject, class: &str, method: &str) -> Option<String> {
+    let jnienv = env.as_jni_env();
+    let (class, method) = env.require_class_method(class, method, "()Ljava/lang/String;\0");
+    (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+    let string = (**jnienv).CallObjectMethodA.unwrap()(jnienv, object, method, null());
+    if clear_exception(env) || string.is_null() { return None; }
+    let result = StringChars::from_env_jstring(env, string).to_string_lossy();
+    (**jnienv).DeleteLocalRef.unwrap()(jnienv, string);
+    Some(result)
+}
+
+/// Clears any pending exception, returning true if there was one.
+unsafe fn clear_exception(env: &Env) -> bool {
+    let jnienv = env.as_jni_env();
+    if (**jnienv).ExceptionCheck.unwrap()(jnienv) == JNI_FALSE { return false; }
+    (**jnienv).ExceptionClear.unwrap()(jnienv);
+    true
+}
+
+impl<'env, T: ThrowableType> From<Local<'env, T>> for JavaException<T> {
+    fn from(local: Local<'env, T>) -> Self {
+        unsafe {
+            let env = Env::from_ptr(local.oae.env);
+            let details = ThrowableDetails::capture(env, local.oae.object, 0);
+            Self { throwable: Global::from(local), details }
+        }
+    }
+}
+
+impl<T: ThrowableType> Deref for JavaException<T> {
+    type Target = ThrowableDetails;
+    fn deref(&self) -> &ThrowableDetails { &self.details }
+}
+
+impl Display for ThrowableDetails {
+    /// Formats like Java's Throwable.toString(), e.g. `java.lang.IllegalStateException: message`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.message.as_ref() {
+            Some(message)   => write!(f, "{}: {}", self.class_name, message),
+            None            => write!(f, "{}", self.class_name),
+        }
+    }
+}
+
+impl Debug for ThrowableDetails {
+    /// Formats like Java's Throwable.printStackTrace(), including causes.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut details = Some(self);
+        let mut prefix = "";
+        while let Some(d) = details {
+            writeln!(f, "{}{}", prefix, d)?;
+            for frame in d.stack_trace.iter() {
+                writeln!(f, "\tat {}", frame)?;
+            }
+            details = d.cause();
+            prefix = "Caused by: ";
+        }
+        Ok(())
+    }
+}
+
+impl<T: ThrowableType> Display for JavaException<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Display::fmt(&self.details, f) }
+}
+
+impl<T: ThrowableType> Debug for JavaException<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Debug::fmt(&self.details, f) }
+}
+
+impl Error for ThrowableDetails {
+    fn source(&self) -> Option<&(dyn Error + 'static)> { self.cause.as_ref().map(|c| &**c as &(dyn Error + 'static)) }
+}
+
+impl<T: ThrowableType> Error for JavaException<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> { self.details.source() }
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn java_exception_outlives_env() {
+    // Exceptions should be rendered eagerly, causes and all, so they can outlive the env and move between threads.
+    let exception = with_test_env(|env|{
+        let cause : Local<TestThrowable> = new_test_object(env, "java/lang/IllegalMonitorStateException\0");
+        let (class, ctor) = unsafe { env.require_class_method("java/lang/IllegalStateException\0", "<init>\0", "(Ljava/lang/String;Ljava/lang/Throwable;)V\0") };
+        let args = [jvalue { l: env.new_string_from_str("outer") }, jvalue { l: cause.oae.object }];
+        assert_eq!(ThrowableDetails::of(&*cause).class_name(), "java.lang.IllegalMonitorStateException");
+        let exception : JavaException<TestThrowable> = unsafe { env.new_object_a::<TestThrowable, TestThrowable>(class, ctor, args.as_ptr()) }.unwrap().into();
+        assert_eq!(exception.to_string(), "java.lang.IllegalStateException: outer");
+        assert_eq!(ThrowableDetails::cause(&exception).map(|c| c.class_name()), Some("java.lang.IllegalMonitorStateException"));
+        assert!(Error::source(&exception).is_some());
+        exception
+    });
+
+    std::thread::spawn(move ||{
+        assert!(format!("{:?}", exception).contains("Caused by: java.lang.IllegalMonitorStateException"));
+        drop(exception);
+    }).join().unwrap();
+}
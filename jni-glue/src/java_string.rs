@@ -0,0 +1,289 @@
+use super::*;
+
+use std::borrow::{Borrow, Cow};
+use std::char;
+use std::ffi::{OsStr, OsString};
+use std::fmt::{self, Debug, Display, Formatter, Write};
+use std::ops::Deref;
+use std::str;
+
+
+
+/// A borrowed, [WTF-8](https://simonsapin.github.io/wtf-8/) encoded Java string.  Unlike [str], this can represent any
+/// sequence of [jchar]s - including unpaired surrogates, which Java strings may legally contain - without loss.
+///
+/// [str]:      https://doc.rust-lang.org/std/primitive.str.html
+/// [jchar]:    struct.jchar.html
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JavaStr {
+    bytes: [u8],
+}
+
+/// An owned, [WTF-8](https://simonsapin.github.io/wtf-8/) encoded Java string.  Unlike [String], this can represent
+/// any sequence of [jchar]s - including unpaired surrogates, which Java strings may legally contain - without loss.
+///
+/// [String]:   https://doc.rust-lang.org/std/string/struct.String.html
+/// [jchar]:    struct.jchar.html
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JavaString {
+    bytes: Vec<u8>,
+}
+
+impl JavaStr {
+    fn from_bytes_unchecked(bytes: &[u8]) -> &JavaStr {
+        unsafe { &*(bytes as *const [u8] as *const JavaStr) }
+    }
+
+    /// The WTF-8 encoded bytes of this string.  These are only valid UTF-8 if the string contains no unpaired
+    /// surrogates.
+    pub fn as_bytes(&self) -> &[u8] { &self.bytes }
+
+    pub fn is_empty(&self) -> bool { self.bytes.is_empty() }
+
+    /// Iterate over the Unicode code points of this string.  Unpaired surrogates are returned as-is (0xD800 ..= 0xDFFF).
+    pub fn code_points(&self) -> CodePoints<'_> { CodePoints { bytes: &self.bytes } }
+
+    /// Iterate over the chars of this string, or the unpaired surrogates that prevented decoding them.
+    pub fn chars(&self) -> impl Iterator<Item = Result<char, u16>> + '_ {
+        self.code_points().map(|cp| char::from_u32(cp).ok_or(cp as u16))
+    }
+
+    /// Iterate over the UTF-16 code units of this string.  Lossless: unpaired surrogates are returned as-is.
+    pub fn encode_utf16(&self) -> impl Iterator<Item = u16> + '_ {
+        self.code_points().flat_map(|cp| {
+            let (first, second) = if cp < 0x10000 {
+                (cp as u16, None)
+            } else {
+                let cp = cp - 0x10000;
+                (0xD800 | (cp >> 10) as u16, Some(0xDC00 | (cp & 0x3FF) as u16))
+            };
+            std::iter::once(first).chain(second)
+        })
+    }
+
+    /// Get the [jchar]s of this string.  Lossless: unpaired surrogates are returned as-is.
+    ///
+    /// [jchar]:    struct.jchar.html
+    pub fn to_jchars(&self) -> Vec<jchar> { self.encode_utf16().map(jchar).collect() }
+
+    /// Returns [Some]\(&[str]\) if this string contains no unpaired surrogates.
+    ///
+    /// [Some]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [str]:  https://doc.rust-lang.org/std/primitive.str.html
+    pub fn to_str(&self) -> Option<&str> { str::from_utf8(&self.bytes).ok() }
+
+    /// Returns a [str] with any unpaired surrogates replaced with [REPLACEMENT_CHARACTER]s (`'\u{FFFD}'`.)
+    ///
+    /// [str]:                      https://doc.rust-lang.org/std/primitive.str.html
+    /// [REPLACEMENT_CHARACTER]:    https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        match self.to_str() {
+            Some(s) => Cow::Borrowed(s),
+            None    => Cow::Owned(self.chars().map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER)).collect()),
+        }
+    }
+
+    /// Convert to an [OsString] without loss.  On Windows, unpaired surrogates become unpaired wide characters.  On
+    /// other unix-like platforms, the WTF-8 bytes are used directly.
+    ///
+    /// [OsString]: https://doc.rust-lang.org/std/ffi/struct.OsString.html
+    pub fn to_os_string(&self) -> OsString {
+        #[cfg(windows)] {
+            use std::os::windows::ffi::OsStringExt;
+            OsString::from_wide(&self.encode_utf16().collect::<Vec<_>>())
+        }
+        #[cfg(unix)] {
+            use std::os::unix::ffi::OsStringExt;
+            OsString::from_vec(self.bytes.to_vec())
+        }
+        #[cfg(not(any(windows, unix)))] {
+            OsString::from(self.to_string_lossy().into_owned())
+        }
+    }
+}
+
+impl JavaString {
+    pub fn new() -> Self { Self::default() }
+
+    /// Decode UTF-16 code units without loss.  Unpaired surrogates are preserved.
+    pub fn from_utf16(units: impl IntoIterator<Item = u16>) -> Self {
+        let mut string = Self::new();
+        for unit in units { string.push_code_point(unit as u32); }
+        string
+    }
+
+    /// Decode [jchar]s without loss.  Unpaired surrogates are preserved.
+    ///
+    /// [jchar]:    struct.jchar.html
+    pub fn from_jchars(chars: &[jchar]) -> Self { Self::from_utf16(chars.iter().map(|ch| ch.0)) }
+
+    /// Convert from an [OsStr] without loss.  Returns None on unix-like platforms if `s` isn't valid WTF-8.
+    ///
+    /// [OsStr]:    https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+    pub fn from_os_str(s: &OsStr) -> Option<Self> {
+        #[cfg(windows)] {
+            use std::os::windows::ffi::OsStrExt;
+            Some(Self::from_utf16(s.encode_wide()))
+        }
+        #[cfg(unix)] {
+            use std::os::unix::ffi::OsStrExt;
+            let bytes = s.as_bytes();
+            let mut string = Self::new();
+            for cp in (CodePoints { bytes }) {
+                if cp > 0x10FFFF { return None; }
+                string.push_code_point(cp);
+            }
+            if string.bytes == bytes { Some(string) } else { None } // Malformed, or a surrogate pair encoded as two halves
+        }
+        #[cfg(not(any(windows, unix)))] {
+            s.to_str().map(Self::from)
+        }
+    }
+
+    /// Returns the [String] if this contains no unpaired surrogates, or else gives back self.
+    ///
+    /// [String]:   https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn into_string(self) -> Result<String, Self> {
+        String::from_utf8(self.bytes).map_err(|err| Self { bytes: err.into_bytes() })
+    }
+
+    pub fn push(&mut self, ch: char) { self.push_code_point(ch as u32) }
+
+    pub fn push_str(&mut self, s: &str) { self.bytes.extend_from_slice(s.as_bytes()) }
+
+    /// Push a Unicode code point, which may be an unpaired surrogate.  A trailing surrogate immediately following a
+    /// leading surrogate will be joined together into a single supplementary code point.
+    pub fn push_code_point(&mut self, cp: u32) {
+        assert!(cp <= 0x10FFFF, "invalid code point: {:#x}", cp);
+        let len = self.bytes.len();
+        if (0xDC00..=0xDFFF).contains(&cp) && len >= 3 && self.bytes[len-3] == 0xED && (0xA0..=0xAF).contains(&self.bytes[len-2]) {
+            let lead = 0xD000 | ((self.bytes[len-2] as u32 & 0x3F) << 6) | (self.bytes[len-1] as u32 & 0x3F);
+            self.bytes.truncate(len-3);
+            return self.push_code_point(0x10000 + ((lead - 0xD800) << 10) + (cp - 0xDC00));
+        }
+
+        match cp {
+            0x00 ..= 0x7F   => self.bytes.push(cp as u8),
+            0x80 ..= 0x7FF  => self.bytes.extend_from_slice(&[0xC0 | (cp >> 6) as u8, 0x80 | (cp & 0x3F) as u8]),
+            0x800 ..= 0xFFFF => self.bytes.extend_from_slice(&[0xE0 | (cp >> 12) as u8, 0x80 | ((cp >> 6) & 0x3F) as u8, 0x80 | (cp & 0x3F) as u8]),
+            _               => self.bytes.extend_from_slice(&[0xF0 | (cp >> 18) as u8, 0x80 | ((cp >> 12) & 0x3F) as u8, 0x80 | ((cp >> 6) & 0x3F) as u8, 0x80 | (cp & 0x3F) as u8]),
+        }
+    }
+}
+
+/// Iterates over the code points of a [JavaStr].  Created by [JavaStr::code_points].
+///
+/// [JavaStr]:              struct.JavaStr.html
+/// [JavaStr::code_points]: struct.JavaStr.html#method.code_points
+pub struct CodePoints<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for CodePoints<'a> {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> {
+        let bytes = self.bytes;
+        let lead = *bytes.first()?;
+        let (len, mask) = match lead {
+            0x00 ..= 0x7F   => (1, 0x7F),
+            0xC0 ..= 0xDF   => (2, 0x1F),
+            0xE0 ..= 0xEF   => (3, 0x0F),
+            0xF0 ..= 0xF7   => (4, 0x07),
+            _               => (0, 0x00),
+        };
+        if len == 0 || bytes.len() < len || bytes[1..len].iter().any(|b| b & 0xC0 != 0x80) {
+            self.bytes = &bytes[1..];
+            return Some(0xFFFD); // Only reachable via from_os_str's validation of foreign bytes
+        }
+        let cp = bytes[1..len].iter().fold(lead as u32 & mask, |cp, b| (cp << 6) | (*b as u32 & 0x3F));
+        self.bytes = &bytes[len..];
+        Some(cp)
+    }
+}
+
+impl Deref for JavaString {
+    type Target = JavaStr;
+    fn deref(&self) -> &JavaStr { JavaStr::from_bytes_unchecked(&self.bytes) }
+}
+
+impl Borrow<JavaStr> for JavaString {
+    fn borrow(&self) -> &JavaStr { self }
+}
+
+impl ToOwned for JavaStr {
+    type Owned = JavaString;
+    fn to_owned(&self) -> JavaString { JavaString { bytes: self.bytes.to_vec() } }
+}
+
+impl AsRef<JavaStr> for JavaStr     { fn as_ref(&self) -> &JavaStr { self } }
+impl AsRef<JavaStr> for JavaString  { fn as_ref(&self) -> &JavaStr { self } }
+impl AsRef<JavaStr> for str         { fn as_ref(&self) -> &JavaStr { JavaStr::from_bytes_unchecked(self.as_bytes()) } }
+impl AsRef<JavaStr> for String      { fn as_ref(&self) -> &JavaStr { self.as_str().as_ref() } }
+
+impl From<&str>         for JavaString { fn from(s: &str)       -> Self { Self { bytes: s.as_bytes().to_vec() } } }
+impl From<String>       for JavaString { fn from(s: String)     -> Self { Self { bytes: s.into_bytes() } } }
+impl From<&JavaStr>     for JavaString { fn from(s: &JavaStr)   -> Self { s.to_owned() } }
+impl From<JavaString>   for OsString   { fn from(s: JavaString) -> Self { s.to_os_string() } }
+
+impl Debug for JavaStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for ch in self.chars() {
+            match ch {
+                Ok(ch)      => for ch in ch.escape_debug() { f.write_char(ch)?; },
+                Err(unit)   => write!(f, "\\u{{{:X}}}", unit)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl Debug for JavaString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Debug::fmt(&**self, f) }
+}
+
+impl Display for JavaStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Display::fmt(&self.to_string_lossy(), f) }
+}
+
+impl Display for JavaString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Display::fmt(&**self, f) }
+}
+
+#[test] fn java_string_round_trip() {
+    let cases : &[&[u16]] = &[
+        &[],
+        &[0x61, 0x62, 0x63],            // "abc"
+        &[0x00E9, 0x4E2D],              // BMP
+        &[0xD83D, 0xDE00],              // U+1F600, paired
+        &[0xD83D, 0x61],                // Unpaired lead
+        &[0x61, 0xDE00],                // Unpaired trail
+        &[0xDE00, 0xD83D],              // Reversed pair - two unpaired surrogates
+    ];
+    for units in cases.iter() {
+        let string = JavaString::from_utf16(units.iter().cloned());
+        assert_eq!(string.encode_utf16().collect::<Vec<_>>(), *units);
+        assert_eq!(JavaString::from_os_str(&string.to_os_string()).as_ref(), Some(&string));
+        assert_eq!(string.to_str().is_some(), String::from_utf16(units).is_ok());
+    }
+
+    let smiley = JavaString::from_utf16([0xD83D, 0xDE00].iter().cloned());
+    assert_eq!(smiley.as_bytes(), "\u{1F600}".as_bytes());
+    assert_eq!(smiley.into_string().unwrap(), "\u{1F600}");
+
+    let unpaired = JavaString::from_utf16([0x61, 0xD83D].iter().cloned());
+    assert_eq!(unpaired.chars().collect::<Vec<_>>(), vec![Ok('a'), Err(0xD83D)]);
+    assert_eq!(unpaired.to_string_lossy(), "a\u{FFFD}");
+    assert_eq!(format!("{:?}", unpaired), "\"a\\u{D83D}\"");
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn java_string_unpaired_surrogates() {
+    // Unpaired surrogates should survive the round trip through a java.lang.String.
+    with_test_env(|env|{
+        let text = JavaString::from_utf16([0x61, 0xD83D, 0x62].iter().cloned());
+        let chars = text.to_jchars();
+        let string = unsafe { env.new_string(chars.as_ptr(), chars.len() as jsize) };
+        assert_eq!(unsafe { StringChars::from_env_jstring(env, string) }.to_java_string(), text);
+    });
+}
@@ -0,0 +1,207 @@
+use super::*;
+use std::io;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+
+type JniCreateJavaVM = unsafe extern "system" fn(pvm: *mut *mut JavaVM, penv: *mut *mut c_void, args: *mut c_void) -> jint;
+
+/// Creates a new JVM from Rust via the [JNI Invocation API](https://docs.oracle.com/javase/8/docs/technotes/guides/jni/spec/invocation.html).
+///
+/// Most JVMs (including HotSpot) only allow a single VM to be created per process, ever - even if you destroy the
+/// first one.  Since jni-glue otherwise only learns about VMs via JNI_OnLoad, this is mostly useful for hosting a JVM
+/// from a Rust executable, or integration testing generated bindings on desktop against a local JDK.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// let vm = JavaVMBuilder::new()
+///     .classpath(&["target/classes"])
+///     .check_jni()
+///     .property("file.encoding", "UTF-8")
+///     .build()
+///     .unwrap();
+///
+/// vm.with_env(|env|{
+///     // ...
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct JavaVMBuilder {
+    libjvm:                 Option<PathBuf>,
+    version:                jint,
+    options:                Vec<String>,
+    ignore_unrecognized:    bool,
+}
+
+impl Default for JavaVMBuilder {
+    fn default() -> Self { Self::new() }
+}
+
+impl JavaVMBuilder {
+    pub fn new() -> Self {
+        Self {
+            libjvm:                 None,
+            version:                JNI_VERSION_1_8,
+            options:                Vec::new(),
+            ignore_unrecognized:    false,
+        }
+    }
+
+    /// Load the JVM from an explicit path (e.g. ".../lib/server/libjvm.so") instead of searching `JAVA_HOME`.
+    pub fn libjvm(mut self, path: impl Into<PathBuf>) -> Self {
+        self.libjvm = Some(path.into());
+        self
+    }
+
+    /// The JNI version to request.  Defaults to JNI_VERSION_1_8.
+    pub fn version(mut self, version: jint) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Add a raw JVM option string such as "-Xmx512m" or "-verbose:jni".
+    pub fn option(mut self, option: impl Into<String>) -> Self {
+        self.options.push(option.into());
+        self
+    }
+
+    /// Add "-Djava.class.path=..." with the given paths joined by the platform's path separator.
+    pub fn classpath<P: AsRef<Path>>(self, paths: impl IntoIterator<Item = P>) -> Self {
+        let separator = if cfg!(windows) { ";" } else { ":" };
+        let paths = paths.into_iter().map(|p| p.as_ref().display().to_string()).collect::<Vec<_>>();
+        self.option(format!("-Djava.class.path={}", paths.join(separator)))
+    }
+
+    /// Add "-Xcheck:jni", enabling additional (slow) checks for misuse of JNI functions.
+    pub fn check_jni(self) -> Self {
+        self.option("-Xcheck:jni")
+    }
+
+    /// Add "-D{key}={value}", defining a Java system property.
+    pub fn property(self, key: &str, value: &str) -> Self {
+        self.option(format!("-D{}={}", key, value))
+    }
+
+    /// Should JNI_CreateJavaVM ignore options it doesn't recognize instead of failing?  Defaults to false.
+    pub fn ignore_unrecognized(mut self, ignore: bool) -> Self {
+        self.ignore_unrecognized = ignore;
+        self
+    }
+
+    /// Load libjvm, call JNI_CreateJavaVM, and register the resulting VM with jni-glue.  The current thread will be
+    /// attached to the new VM.
+    pub fn build(self) -> io::Result<OwnedVM> {
+        let libjvm = match self.libjvm {
+            Some(path) => path,
+            None => find_libjvm()?,
+        };
+
+        let options = self.options.iter().map(|option| {
+            CString::new(option.as_str()).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("JVM option {:?} contains an interior nul", option)))
+        }).collect::<io::Result<Vec<_>>>()?;
+        let mut jvm_options = options.iter().map(|option| JavaVMOption {
+            optionString:   option.as_ptr() as *mut c_char,
+            extraInfo:      null_mut(),
+        }).collect::<Vec<_>>();
+
+        let mut args = JavaVMInitArgs {
+            version:            self.version,
+            nOptions:           jvm_options.len() as jint,
+            options:            jvm_options.as_mut_ptr(),
+            ignoreUnrecognized: if self.ignore_unrecognized { JNI_TRUE } else { JNI_FALSE },
+        };
+
+        // N.B. libjvm is intentionally leaked: JVMs generally don't support being unloaded, even after DestroyJavaVM.
+        let library = unsafe { libloading::Library::new(&libjvm) }.map_err(|e| io::Error::new(io::ErrorKind::NotFound, format!("Unable to load {}: {}", libjvm.display(), e)))?;
+        let library = Box::leak(Box::new(library));
+        let create_java_vm = unsafe { library.get::<JniCreateJavaVM>(b"JNI_CreateJavaVM\0") }.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{} doesn't export JNI_CreateJavaVM: {}", libjvm.display(), e)))?;
+
+        let mut vm  = null_mut();
+        let mut env = null_mut();
+        match unsafe { create_java_vm(&mut vm, &mut env, &mut args as *mut JavaVMInitArgs as *mut c_void) } {
+            JNI_OK          => {},
+            JNI_EEXIST      => return Err(io::Error::new(io::ErrorKind::AlreadyExists,  "JNI_CreateJavaVM failed: a VM has already been created in this process")),
+            JNI_EVERSION    => return Err(io::Error::new(io::ErrorKind::InvalidInput,   format!("JNI_CreateJavaVM failed: JNI version {:#x} is not supported", self.version))),
+            JNI_ENOMEM      => return Err(io::Error::other(                             "JNI_CreateJavaVM failed: out of memory")),
+            JNI_EINVAL      => return Err(io::Error::new(io::ErrorKind::InvalidInput,   "JNI_CreateJavaVM failed: invalid arguments")),
+            unexpected      => return Err(io::Error::other(                             format!("JNI_CreateJavaVM returned unknown error: {}", unexpected))),
+        }
+        assert!(!vm.is_null());
+
+        unsafe { VMS.write().unwrap().on_load(vm) };
+        Ok(OwnedVM { vm })
+    }
+}
+
+/// Search for libjvm in `JAVA_HOME`.
+fn find_libjvm() -> io::Result<PathBuf> {
+    let java_home = std::env::var_os("JAVA_HOME").ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "JAVA_HOME not defined - unable to locate libjvm"))?;
+    let java_home = PathBuf::from(java_home);
+
+    let candidates : &[&str] = if cfg!(windows) {
+        &["bin/server/jvm.dll", "jre/bin/server/jvm.dll", "bin/client/jvm.dll", "jre/bin/client/jvm.dll"]
+    } else if cfg!(target_os = "macos") {
+        &["lib/server/libjvm.dylib", "jre/lib/server/libjvm.dylib"]
+    } else if cfg!(target_arch = "x86_64") {
+        &["lib/server/libjvm.so", "jre/lib/amd64/server/libjvm.so", "lib/amd64/server/libjvm.so"]
+    } else if cfg!(target_arch = "aarch64") {
+        &["lib/server/libjvm.so", "jre/lib/aarch64/server/libjvm.so", "lib/aarch64/server/libjvm.so"]
+    } else if cfg!(target_arch = "x86") {
+        &["lib/server/libjvm.so", "jre/lib/i386/server/libjvm.so", "lib/i386/server/libjvm.so", "jre/lib/i386/client/libjvm.so"]
+    } else {
+        &["lib/server/libjvm.so"]
+    };
+
+    for candidate in candidates {
+        let path = java_home.join(candidate);
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    Err(io::Error::new(io::ErrorKind::NotFound, format!("Unable to locate libjvm in JAVA_HOME ({})", java_home.display())))
+}
+
+
+
+/// A [VM] created by [JavaVMBuilder], which will be unregistered and DestroyJavaVM()ed when dropped.
+///
+/// DestroyJavaVM will block until all other non-daemon Java threads have exited.  Any [Global]s that outlive this will
+/// be invalidated.
+///
+/// [VM]:               struct.VM.html
+/// [JavaVMBuilder]:    struct.JavaVMBuilder.html
+/// [Global]:           struct.Global.html
+pub struct OwnedVM {
+    vm: *mut JavaVM,
+}
+
+unsafe impl Send for OwnedVM {}
+unsafe impl Sync for OwnedVM {}
+
+impl Deref for OwnedVM {
+    type Target = VM;
+    fn deref(&self) -> &Self::Target {
+        unsafe { VM::from_jni_local(&*self.vm) }
+    }
+}
+
+impl Drop for OwnedVM {
+    fn drop(&mut self) {
+        unload_vm(self.vm);
+        let vm = self.vm;
+        let result = unsafe { (**vm).DestroyJavaVM.unwrap()(vm) };
+        debug_assert_eq!(result, JNI_OK);
+    }
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn java_vm_builder_build() {
+    // Properties passed to the builder should be visible to Java.
+    with_test_env(|env| unsafe {
+        let (system, get_property) = env.require_class_static_method("java/lang/System\0", "getProperty\0", "(Ljava/lang/String;)Ljava/lang/String;\0");
+        let key = env.new_string_from_str("jni.glue.test");
+        let value = env.call_static_object_method_a::<TestString, TestThrowable>(system, get_property, [jvalue { l: key }].as_ptr()).unwrap().unwrap();
+        assert_eq!(StringChars::from_env_jstring(env, value.0.object).to_string_lossy(), "1");
+    });
+}
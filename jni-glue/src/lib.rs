@@ -7,6 +7,8 @@
 #[doc(hidden)] pub use ::std as std;
 #[doc(hidden)] pub use ::jni_sys as jni_sys;
 
+pub use jni_glue_macros::native;
+
 use jni_sys::*;
 use lazy_static::*;
 
@@ -14,14 +16,16 @@ use std::ffi::*;
 use std::ptr::*;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::sync::*;
+use std::sync::RwLock;
 
-pub(crate) mod backends { // XXX: Might expose this to the end user in order to let them choose which backend to use...?
+pub(crate) mod backends { // See the "multi-vm" feature to choose which backend to use.
     use super::*;
 
-    mod single_vm_backend;
+    #[cfg(feature = "multi-vm")]        mod multi_vm_backend;
+    #[cfg(not(feature = "multi-vm"))]   mod single_vm_backend;
 
-    pub(crate) use single_vm_backend::*;
+    #[cfg(feature = "multi-vm")]        pub(crate) use multi_vm_backend::*;
+    #[cfg(not(feature = "multi-vm"))]   pub(crate) use single_vm_backend::*;
 }
 
 mod refs {
@@ -31,42 +35,74 @@ mod refs {
     mod global;
     mod local;
     mod ref_;
+    mod weak;
 
     pub use argument::*;
     pub use global::*;
     pub use local::*;
     pub use ref_::*;
+    pub use weak::*;
 }
 
 mod __jni_bindgen;
 mod array;
+mod array_elements;
 mod as_jvalue;
+mod attach_guard;
 mod as_valid_jobject_and_env;
 mod env;
 mod gen_vm;
+mod included_class;
+mod into_java_string;
+mod java_catch;
+mod java_exception;
+mod java_string;
+#[cfg(any(test, feature = "invocation"))] mod java_vm_builder;
 mod jchar_;
 mod jni_type;
+mod modified_utf8;
+mod monitor_guard;
+mod native;
+mod native_methods;
 mod object_and_env;
+mod proxy;
 mod string_chars;
+#[cfg(test)] mod test_vm;
+mod throw_result;
 mod throwable_type;
 mod vm;
 
 pub use array::*;
+pub use array_elements::*;
 pub use as_jvalue::*;
+pub use attach_guard::*;
 pub use as_valid_jobject_and_env::*;
 pub use env::*;
 pub(crate) use gen_vm::*;
+pub use included_class::*;
+pub use into_java_string::*;
+pub use java_exception::*;
+pub use java_string::*;
+#[cfg(any(test, feature = "invocation"))] pub use java_vm_builder::*;
 pub use jchar_::{jchar, *};
 pub use jni_type::JniType;
+pub use modified_utf8::*;
+pub use monitor_guard::*;
+pub use native::*;
+pub use native_methods::*;
 pub use object_and_env::*;
+pub use proxy::*;
 pub use refs::*;
 pub use string_chars::*;
+#[cfg(test)] pub(crate) use test_vm::*;
+pub use throw_result::*;
 pub use throwable_type::*;
 pub use vm::*;
 
 
 
-type VmBackend = backends::SingleVmBackend;
+#[cfg(not(feature = "multi-vm"))]   type VmBackend = backends::SingleVmBackend;
+#[cfg(feature = "multi-vm")]        type VmBackend = backends::MultiVmBackend;
 lazy_static! { // RwLock::new is not const
     static ref VMS : RwLock<VmBackend> = RwLock::new(VmBackend::new());
 }
@@ -81,18 +117,44 @@ pub unsafe fn on_load(vm: *const JavaVM, _reserved: *const c_void) -> jint {
 /// **Disable "unsafe-manual-jni-load-unload", or call from JNI_OnUnload, or there will be soundness issues!**
 #[cfg(feature = "unsafe-manual-jni-load-unload")]
 pub fn on_unload(vm: *const JavaVM, _reserved: *const c_void) {
-    VMS.write().unwrap().on_unload(vm);
+    unload_vm(vm);
 }
 
 /// **Do not call!**  Automatically invoked by the JVM.  See "unsafe-manual-jni-load-unload" to override this behavior.
+///
+/// With the "on-load-hook" feature, this also calls the hook defined with [on_load!], failing to load if the hook
+/// panics or throws.
+///
+/// [on_load!]: macro.on_load.html
 #[no_mangle] #[allow(non_snake_case)] #[cfg(not(feature = "unsafe-manual-jni-load-unload"))]
 pub unsafe extern "system" fn JNI_OnLoad(vm: *const JavaVM, _reserved: *const c_void) -> jint {
     VMS.write().unwrap().on_load(vm);
+
+    #[cfg(feature = "on-load-hook")] {
+        extern "Rust" { fn __jni_glue_on_load(env: &Env) -> bool; } // Defined by on_load!
+        let mut env = null_mut();
+        let ok = (**vm).GetEnv.unwrap()(vm as *mut JavaVM, &mut env, JNI_VERSION_1_2) == JNI_OK
+            && __jni_glue_on_load(Env::from_ptr(env as *const JNIEnv));
+        if !ok {
+            unload_vm(vm);
+            return JNI_ERR;
+        }
+    }
+
     JNI_VERSION_1_2
 }
 
 /// **Do not call!**  Automatically invoked by the JVM.  See "unsafe-manual-jni-load-unload" to override this behavior.
 #[no_mangle] #[allow(non_snake_case)] #[cfg(not(feature = "unsafe-manual-jni-load-unload"))]
 pub extern "system" fn JNI_OnUnload(vm: *const JavaVM, _reserved: *const c_void) {
-    VMS.write().unwrap().on_unload(vm);
+    unload_vm(vm);
+}
+
+/// Invalidates `vm`, and forgets the classes, method IDs, etc. cached for it.  The cached global references are leaked
+/// rather than deleted, as the VM is going away - and we may not even have a thread attached to it.
+pub(crate) fn unload_vm(vm: *const JavaVM) {
+    let gen_vm = VMS.write().unwrap().on_unload(vm);
+    env::forget_vm(gen_vm);
+    included_class::forget_vm(gen_vm);
+    proxy::forget_vm(gen_vm);
 }
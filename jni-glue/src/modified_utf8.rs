@@ -0,0 +1,132 @@
+//! JNI's [modified UTF-8](https://docs.oracle.com/javase/8/docs/technotes/guides/jni/spec/types.html#modified_utf_8_strings)
+//! encoding, as used by NewStringUTF, GetStringUTFChars, class/method names, etc.
+//!
+//! This differs from standard UTF-8 in two ways:
+//!
+//! 1)  NUL is encoded as the overlong two byte sequence `C0 80`, so encoded strings never contain a `00` byte.
+//! 2)  Supplementary characters (U+10000 and up) are encoded as a UTF-16 surrogate pair, each half encoded as a
+//!     separate three byte sequence (a.k.a. CESU-8), instead of a single four byte sequence.
+
+use std::borrow::Cow;
+use std::char::{self, DecodeUtf16Error};
+use std::str;
+
+
+
+/// Encode `string` as modified UTF-8 (without a terminating NUL.)  Borrows `string` if it's already valid modified
+/// UTF-8 - that is, if it contains no NULs nor supplementary characters, which is the overwhelmingly common case.
+pub fn encode_modified_utf8(string: &str) -> Cow<'_, [u8]> {
+    if is_modified_utf8_compatible(string) {
+        return Cow::Borrowed(string.as_bytes());
+    }
+
+    let mut encoded = Vec::with_capacity(string.len() + 2);
+    for ch in string.chars() {
+        match ch {
+            '\0' => encoded.extend_from_slice(&[0xC0, 0x80]),
+            ch if (ch as u32) < 0x10000 => {
+                let mut buffer = [0u8; 4];
+                encoded.extend_from_slice(ch.encode_utf8(&mut buffer).as_bytes());
+            },
+            ch => {
+                let mut buffer = [0u16; 2];
+                for unit in ch.encode_utf16(&mut buffer).iter() {
+                    let unit = *unit as u32;
+                    encoded.extend_from_slice(&[0xE0 | (unit >> 12) as u8, 0x80 | ((unit >> 6) & 0x3F) as u8, 0x80 | (unit & 0x3F) as u8]);
+                }
+            },
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+/// Is `string`'s standard UTF-8 encoding also valid modified UTF-8?  True if it contains no NULs nor supplementary
+/// characters (which are the only characters with four byte UTF-8 encodings.)
+pub fn is_modified_utf8_compatible(string: &str) -> bool {
+    string.bytes().all(|b| b != 0 && b < 0xF0)
+}
+
+/// Decode modified UTF-8 (without a terminating NUL) into a string.  Borrows `bytes` if they're also valid standard
+/// UTF-8.  Returns an error if `bytes` contains unpaired surrogates - which Java strings may legally contain - or is
+/// otherwise malformed.
+pub fn decode_modified_utf8(bytes: &[u8]) -> Result<Cow<'_, str>, DecodeUtf16Error> {
+    if let Ok(string) = str::from_utf8(bytes) {
+        return Ok(Cow::Borrowed(string));
+    }
+    let mut units = ModifiedUtf8Units { bytes, malformed: false };
+    let string = char::decode_utf16(&mut units).collect::<Result<String, _>>()?;
+    if units.malformed {
+        // DecodeUtf16Error can't be constructed directly, so report malformed sequences as an unpaired surrogate.
+        return Err(char::decode_utf16([0xDC00]).next().unwrap().unwrap_err());
+    }
+    Ok(Cow::Owned(string))
+}
+
+/// Decode modified UTF-8 (without a terminating NUL) into a string, replacing unpaired surrogates and malformed
+/// sequences with [REPLACEMENT_CHARACTER]s (`'\u{FFFD}'`.)  Borrows `bytes` if they're also valid standard UTF-8.
+///
+/// [REPLACEMENT_CHARACTER]:    https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html
+pub fn decode_modified_utf8_lossy(bytes: &[u8]) -> Cow<'_, str> {
+    if let Ok(string) = str::from_utf8(bytes) {
+        return Cow::Borrowed(string);
+    }
+    Cow::Owned(char::decode_utf16(ModifiedUtf8Units { bytes, malformed: false }).map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER)).collect())
+}
+
+/// Iterates over the UTF-16 code units encoded by modified UTF-8 bytes.  Malformed sequences yield a replacement
+/// character (0xFFFD) and set `malformed` - not a lone surrogate, which could pair up with a preceding one.
+struct ModifiedUtf8Units<'a> {
+    bytes:      &'a [u8],
+    malformed:  bool,
+}
+
+impl<'a> Iterator for ModifiedUtf8Units<'a> {
+    type Item = u16;
+    fn next(&mut self) -> Option<u16> {
+        const MALFORMED : u16 = 0xFFFD;
+        let bytes = self.bytes;
+        let lead = *bytes.first()?;
+        let continuation = |i: usize| bytes.get(i).filter(|b| *b & 0xC0 == 0x80).map(|b| (*b & 0x3F) as u16);
+
+        let (unit, len) = if lead < 0x80 {
+            (lead as u16, 1)
+        } else if lead & 0xE0 == 0xC0 {
+            match continuation(1) {
+                Some(b1)    => (((lead & 0x1F) as u16) << 6 | b1, 2),
+                None        => (MALFORMED, 1),
+            }
+        } else if lead & 0xF0 == 0xE0 {
+            match (continuation(1), continuation(2)) {
+                (Some(b1), Some(b2))    => (((lead & 0x0F) as u16) << 12 | b1 << 6 | b2, 3),
+                _                       => (MALFORMED, 1),
+            }
+        } else {
+            (MALFORMED, 1)
+        };
+
+        if unit == MALFORMED && len == 1 { self.malformed = true; } // A well formed U+FFFD takes 3 bytes
+        self.bytes = &bytes[len..];
+        Some(unit)
+    }
+}
+
+#[test] fn modified_utf8_round_trip() {
+    let cases : &[(&str, &[u8])] = &[
+        ("",                &[]),
+        ("ascii",           b"ascii"),
+        ("nul\0nul",        b"nul\xC0\x80nul"),
+        ("\u{00E9}\u{4E2D}", "\u{00E9}\u{4E2D}".as_bytes()),
+        ("\u{1F600}",       b"\xED\xA0\xBD\xED\xB8\x80"), // U+1F600 => D83D DE00
+    ];
+    for (string, encoded) in cases.iter() {
+        assert_eq!(&*encode_modified_utf8(string), *encoded);
+        assert_eq!(decode_modified_utf8(encoded).unwrap(), *string);
+        assert_eq!(decode_modified_utf8_lossy(encoded), *string);
+    }
+
+    assert!(matches!(encode_modified_utf8("ascii"), Cow::Borrowed(_)));
+    assert!(decode_modified_utf8(b"\xED\xA0\xBD").is_err()); // Unpaired surrogate
+    assert_eq!(decode_modified_utf8_lossy(b"a\xED\xA0\xBDb"), "a\u{FFFD}b");
+    assert!(decode_modified_utf8(b"\xED\xA0\xBD\xFF").is_err()); // Unpaired surrogate, *then* a malformed byte
+    assert_eq!(decode_modified_utf8_lossy(b"\xED\xA0\xBD\xFF"), "\u{FFFD}\u{FFFD}");
+}
@@ -0,0 +1,99 @@
+use super::*;
+
+
+
+/// Holds a Java object's monitor until dropped, like a Java `synchronized (object) { ... }` block.  Created by
+/// [Ref::synchronized] or [Local::synchronized].
+///
+/// Monitors are reentrant - a thread may enter the same object's monitor multiple times, and must exit it as many
+/// times before other threads may enter it.  Guards should be dropped in the reverse order they were created.
+///
+/// Not Send: monitors are owned by threads, so this cannot be sent to another thread.
+///
+/// [Ref::synchronized]:    struct.Ref.html#method.synchronized
+/// [Local::synchronized]:  struct.Local.html#method.synchronized
+pub struct MonitorGuard<'a> {
+    env:        *mut JNIEnv,
+    object:     jobject,
+    _object:    PhantomData<&'a ()>,
+}
+
+impl<'a> MonitorGuard<'a> {
+    /// Uses env.MonitorEnter to enter `object`'s monitor, blocking until it's available.
+    pub(crate) unsafe fn enter<'env, E: ThrowableType>(env: *mut JNIEnv, object: jobject) -> Result<Self, Local<'env, E>> {
+        let result = (**env).MonitorEnter.unwrap()(env, object);
+        let exception = (**env).ExceptionOccurred.unwrap()(env);
+        if !exception.is_null() {
+            (**env).ExceptionClear.unwrap()(env);
+            Err(Local::from_env_object(env, exception))
+        } else {
+            assert_eq!(result, JNI_OK, "MonitorEnter failed without throwing an exception");
+            Ok(MonitorGuard { env, object, _object: PhantomData })
+        }
+    }
+
+    /// Uses env.MonitorExit to exit the monitor, returning any exception (such as java.lang.IllegalMonitorStateException
+    /// if the current thread no longer owns the monitor) instead of discarding it like dropping the guard would.
+    ///
+    /// An exception that was already pending (e.g. thrown while holding the monitor) is left pending, not returned.
+    pub fn exit<E: ThrowableType>(self) -> Result<(), Local<'a, E>> {
+        let (env, object) = (self.env, self.object);
+        std::mem::forget(self);
+        unsafe {
+            let pending = (**env).ExceptionCheck.unwrap()(env) != JNI_FALSE;
+            (**env).MonitorExit.unwrap()(env, object);
+            if pending { return Ok(()); }
+            let exception = (**env).ExceptionOccurred.unwrap()(env);
+            if !exception.is_null() {
+                (**env).ExceptionClear.unwrap()(env);
+                Err(Local::from_env_object(env, exception))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> Drop for MonitorGuard<'a> {
+    fn drop(&mut self) {
+        let env = self.env;
+        unsafe {
+            // An exception thrown while holding the monitor is left pending for the caller - MonitorExit may be called
+            // with one pending.
+            let pending = (**env).ExceptionCheck.unwrap()(env) != JNI_FALSE;
+            (**env).MonitorExit.unwrap()(env, self.object);
+            // There's nowhere to report an exception from MonitorExit itself, and leaving it pending would poison the
+            // next JNI call.  Use exit() instead if you care.
+            if !pending && (**env).ExceptionCheck.unwrap()(env) != JNI_FALSE {
+                (**env).ExceptionClear.unwrap()(env);
+            }
+        }
+    }
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn monitor_guard_exit() {
+    // Monitors should be exitable both explicitly and by dropping, and report not being owned.
+    with_test_env(|env|{
+        let object : Local<TestThrowable> = new_test_object(env, "java/lang/Throwable\0");
+        drop(object.synchronized::<TestThrowable>().unwrap());
+        assert!(object.synchronized::<TestThrowable>().unwrap().exit::<TestThrowable>().is_ok());
+        let guard = object.synchronized::<TestThrowable>().unwrap();
+        unsafe { (**env.as_jni_env()).MonitorExit.unwrap()(env.as_jni_env(), object.oae.object) };
+        let exception = JavaException::from(guard.exit::<TestThrowable>().unwrap_err());
+        assert_eq!(exception.class_name(), "java.lang.IllegalMonitorStateException");
+    });
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn monitor_guard_keeps_pending() {
+    // Exceptions thrown while holding the monitor should still be pending after it's exited.
+    with_test_env(|env|{
+        let object : Local<TestThrowable> = new_test_object(env, "java/lang/Throwable\0");
+        let jnienv = env.as_jni_env();
+        for explicit in [false, true] {
+            let guard = object.synchronized::<TestThrowable>().unwrap();
+            unsafe { (**jnienv).Throw.unwrap()(jnienv, object.oae.object) };
+            if explicit { assert!(guard.exit::<TestThrowable>().is_ok()); } else { drop(guard); }
+            assert_eq!(take_pending(env).class_name(), "java.lang.Throwable");
+        }
+    });
+}
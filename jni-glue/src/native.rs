@@ -0,0 +1,209 @@
+use super::*;
+
+use std::any::Any;
+use std::error::Error;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+
+
+/// Parameter types of [native] methods, and how to convert them from the raw JNI types Java passes.
+///
+/// | Rust Type                             | Raw JNI Type  | Notes                                         |
+/// | ------------------------------------- | ------------- | --------------------------------------------- |
+/// | jbyte, jchar, jshort, jint, jlong     | (same)        |                                               |
+/// | jfloat, jdouble                       | (same)        |                                               |
+/// | bool                                  | jboolean      |                                               |
+/// | jobject (jclass, ...)                 | jobject       | For when you really want raw access           |
+/// | [Argument]\<T\>                       | jobject       |                                               |
+/// | Option<[Ref]\<T\>>                    | jobject       | None if null                                  |
+/// | [Ref]\<T\>                            | jobject       | Panics (throwing to Java) if null             |
+///
+/// # Safety
+///
+/// Raw is passed directly across the FFI boundary by generated `extern "system"` fns, so implementations must ensure
+/// Raw is FFI safe and ABI compatible with the JNI type Java passes for this parameter.  from_raw must be sound for
+/// any valid `raw` value of that type (including null, for object types) while `env` is the current thread's [Env].
+///
+/// [native]:   attr.native.html
+/// [Env]:      struct.Env.html
+/// [Argument]: struct.Argument.html
+/// [Ref]:      struct.Ref.html
+pub unsafe trait NativeArg<'env> : Sized {
+    type Raw;
+
+    /// Converts the raw value Java passed for this parameter.
+    ///
+    /// # Safety
+    ///
+    /// `env` must be the current thread's [Env], and `raw` must be a value Java actually passed for a parameter of the
+    /// matching JNI type - for object types, null or a reference to an instance of the parameter's class that stays
+    /// valid for `'env` (such as a native method's local reference arguments.)
+    ///
+    /// [Env]:  struct.Env.html
+    unsafe fn from_raw(env: &'env Env, raw: Self::Raw) -> Self;
+}
+
+/// Return types of [native] methods, and how to convert them into the raw JNI types Java expects.
+///
+/// | Rust Type                             | Raw JNI Type  | Notes                                         |
+/// | ------------------------------------- | ------------- | --------------------------------------------- |
+/// | (), jbyte, jchar, jshort, jint, jlong | (same)        |                                               |
+/// | jfloat, jdouble, jobject              | (same)        |                                               |
+/// | bool                                  | jboolean      |                                               |
+/// | [Local]\<T\>, Option<[Local]\<T\>>    | jobject       |                                               |
+/// | [ThrowResult]\<T\>                    | T             |                                               |
+/// | Result<T, E: [NativeError]>           | T::Raw        | Err(e) is thrown to Java                      |
+///
+/// # Safety
+///
+/// Raw is returned directly across the FFI boundary by generated `extern "system"` fns, so implementations must ensure
+/// Raw is FFI safe and ABI compatible with the JNI type Java expects for this method's return type.  into_raw must
+/// only return values Java can safely receive - e.g. object references must be null or valid local references that
+/// Java now owns.
+///
+/// [native]:       attr.native.html
+/// [Local]:        struct.Local.html
+/// [ThrowResult]:  struct.ThrowResult.html
+/// [NativeError]:  trait.NativeError.html
+pub unsafe trait NativeReturn {
+    type Raw : ThrowReturn;
+    fn into_raw(self, env: &Env) -> Self::Raw;
+}
+
+/// Error types that [native] methods can throw to Java by returning `Err(...)`.  Java exceptions are thrown as-is,
+/// while Rust errors are thrown as a java.lang.RuntimeException with the error's message.
+///
+/// [native]:   attr.native.html
+pub trait NativeError {
+    fn throw(self, env: &Env);
+}
+
+
+
+unsafe impl<'env> NativeArg<'env> for bool { type Raw = jboolean; unsafe fn from_raw(_env: &'env Env, raw: jboolean) -> Self { raw != JNI_FALSE } }
+
+macro_rules! native_arg_return {
+    ( $($ty:ty),* $(,)? ) => {$(
+        unsafe impl<'env> NativeArg<'env> for $ty { type Raw = $ty; unsafe fn from_raw(_env: &'env Env, raw: $ty) -> Self { raw } }
+        unsafe impl NativeReturn for $ty { type Raw = $ty; fn into_raw(self, _env: &Env) -> $ty { self } }
+    )*};
+}
+
+native_arg_return!(jbyte, jchar, jshort, jint, jlong, jfloat, jdouble, jobject);
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> NativeArg<'env> for Argument<T> {
+    type Raw = Argument<T>;
+    unsafe fn from_raw(_env: &'env Env, raw: Argument<T>) -> Self { raw }
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> NativeArg<'env> for Option<Ref<'env, T>> {
+    type Raw = jobject;
+    unsafe fn from_raw(env: &'env Env, raw: jobject) -> Self {
+        if raw.is_null() {
+            None
+        } else {
+            Some(Ref { oae: ObjectAndEnv { object: raw, env: env.as_jni_env() }, _env: PhantomData, _class: PhantomData })
+        }
+    }
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> NativeArg<'env> for Ref<'env, T> {
+    type Raw = jobject;
+    unsafe fn from_raw(env: &'env Env, raw: jobject) -> Self {
+        match Option::<Ref<'env, T>>::from_raw(env, raw) {
+            Some(r) => r,
+            None    => T::static_with_jni_type(|ty| panic!("native method was passed a null {}", ty.trim_end_matches('\0'))),
+        }
+    }
+}
+
+unsafe impl NativeReturn for () {
+    type Raw = ();
+    fn into_raw(self, _env: &Env) {}
+}
+
+unsafe impl NativeReturn for bool {
+    type Raw = jboolean;
+    fn into_raw(self, _env: &Env) -> jboolean { if self { JNI_TRUE } else { JNI_FALSE } }
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> NativeReturn for Local<'env, T> {
+    type Raw = jobject;
+    fn into_raw(self, _env: &Env) -> jobject { Local::leak(self).oae.object } // Java takes ownership of returned local refs
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> NativeReturn for Option<Local<'env, T>> {
+    type Raw = jobject;
+    fn into_raw(self, env: &Env) -> jobject { self.map_or(null_mut(), |local| local.into_raw(env)) }
+}
+
+unsafe impl<T: ThrowReturn> NativeReturn for ThrowResult<T> {
+    type Raw = T;
+    fn into_raw(self, _env: &Env) -> T { self.into_inner() }
+}
+
+unsafe impl<T: NativeReturn, E: NativeError> NativeReturn for Result<T, E> {
+    type Raw = T::Raw;
+    fn into_raw(self, env: &Env) -> T::Raw {
+        match self {
+            Ok(value)   => value.into_raw(env),
+            Err(error)  => { error.throw(env); <T::Raw as ThrowReturn>::placeholder() },
+        }
+    }
+}
+
+
+
+impl<'env, E: ThrowableType> NativeError for Local<'env, E> {
+    fn throw(self, env: &Env) { env.throw(&Local::leak(self)) }
+}
+
+impl<E: ThrowableType> NativeError for JavaException<E> {
+    fn throw(self, env: &Env) { env.throw(&self.throwable().with(env)) }
+}
+
+impl NativeError for String {
+    fn throw(self, env: &Env) { env.throw_new::<RuntimeException>(&self) }
+}
+
+impl NativeError for &str {
+    fn throw(self, env: &Env) { env.throw_new::<RuntimeException>(self) }
+}
+
+impl NativeError for Box<dyn Error + Send + Sync> {
+    fn throw(self, env: &Env) { env.throw_new::<RuntimeException>(&self.to_string()) }
+}
+
+struct RuntimeException;
+unsafe impl JniType for RuntimeException { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/RuntimeException\0") } }
+
+
+
+/// The body of every [native] method:  runs `body`, converting its result into a raw JNI value, and converting panics
+/// into thrown java.lang.RuntimeExceptions instead of unwinding into Java (which is undefined behavior.)
+///
+/// [native]:   attr.native.html
+#[doc(hidden)] // Used by #[native] codegen
+pub fn __jni_native_entry<R: NativeReturn>(env: &Env, body: impl FnOnce() -> R) -> R::Raw {
+    match catch_unwind(AssertUnwindSafe(|| body().into_raw(env))) {
+        Ok(raw) => raw,
+        Err(panic) => {
+            let _ = catch_unwind(AssertUnwindSafe(|| {
+                let jnienv = env.as_jni_env();
+                unsafe { (**jnienv).ExceptionClear.unwrap()(jnienv) }; // Report the panic instead of whatever was pending
+                env.throw_new::<RuntimeException>(&format!("Rust panic: {}", panic_message(&*panic)));
+            }));
+            <R::Raw as ThrowReturn>::placeholder()
+        },
+    }
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    if let Some(message) = panic.downcast_ref::<&'static str>() {
+        message
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "Box<Any>"
+    }
+}
@@ -0,0 +1,328 @@
+use super::*;
+
+
+
+/// A set of native method implementations to bind to a Java class via RegisterNatives, as an alternative to exporting
+/// `Java_...` symbols (which don't survive symbol stripping, static linking into a larger library, or classes whose
+/// names you don't control.)
+///
+/// Each method's descriptor is checked against its fn pointer type when added, panicing on mismatch.  Object types are
+/// only checked for [Argument]\<T\> parameters - raw jobject s will match any object or array type.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # use jni_sys::{jboolean, jint, jobject, JNI_TRUE};
+/// # #[repr(transparent)] pub struct MainActivity(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for MainActivity {}
+/// # unsafe impl AsJValue for MainActivity { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for MainActivity { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("com/example/MainActivity\0") } }
+/// # #[repr(transparent)] pub struct Throwable(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for Throwable {}
+/// # unsafe impl AsJValue for Throwable { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for Throwable { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Throwable\0") } }
+/// # impl ThrowableType for Throwable {}
+/// extern "system" fn on_touch(_env: &Env, _this: jobject, x: jint, y: jint) -> jboolean { JNI_TRUE }
+///
+/// fn register(env: &Env) -> Result<(), Local<Throwable>> {
+///     NativeMethods::for_class::<MainActivity>()
+///         .method("onTouch", "(II)Z", on_touch as extern "system" fn(&Env, jobject, jint, jint) -> jboolean)
+///         .register(env)
+/// }
+/// ```
+///
+/// [Argument]: struct.Argument.html
+pub struct NativeMethods {
+    class:      Vec<u8>, // '\0' terminated
+    methods:    Vec<NativeMethod>,
+}
+
+struct NativeMethod {
+    name:       Vec<u8>, // '\0' terminated
+    signature:  Vec<u8>, // '\0' terminated
+    fn_ptr:     *mut c_void,
+}
+
+impl NativeMethods {
+    /// Start building a set of native methods for the class `C`.
+    pub fn for_class<C: JniType>() -> Self {
+        let class = C::static_with_jni_type(|ty| nul_terminated(ty.trim_end_matches('\0')));
+        Self { class, methods: Vec::new() }
+    }
+
+    /// Add a native method implementation.
+    ///
+    /// **Panics** if `signature` isn't a valid method descriptor, or doesn't match the types of `f`.
+    pub fn method<F: NativeFn>(mut self, name: &str, signature: &str, f: F) -> Self {
+        if let Err(error) = F::check_descriptor(signature) {
+            panic!("NativeMethods::method({:?}, {:?}, ...): {}", name, signature, error);
+        }
+        self.methods.push(NativeMethod { name: nul_terminated(name), signature: nul_terminated(signature), fn_ptr: f.as_ptr() });
+        self
+    }
+
+    /// Uses env.RegisterNatives to bind all methods to the class, returning the thrown exception if the class or any of
+    /// the methods couldn't be found.
+    pub fn register<'env, E: ThrowableType>(&self, env: &'env Env) -> Result<(), Local<'env, E>> {
+        let methods = self.methods.iter().map(|m| JNINativeMethod {
+            name:       m.name.as_ptr() as *mut c_char,
+            signature:  m.signature.as_ptr() as *mut c_char,
+            fnPtr:      m.fn_ptr,
+        }).collect::<Vec<_>>();
+
+        unsafe {
+            let jnienv = env.as_jni_env();
+            let class = self.find_class(env)?;
+            let result = (**jnienv).RegisterNatives.unwrap()(jnienv, class, methods.as_ptr(), methods.len() as jint);
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+            if result != JNI_OK { return Err(take_exception(env)); }
+        }
+        Ok(())
+    }
+
+    /// Uses env.UnregisterNatives to unbind *all* native methods of the class - including any not registered by this
+    /// set of methods - reverting them to their unlinked state.
+    pub fn unregister<'env, E: ThrowableType>(&self, env: &'env Env) -> Result<(), Local<'env, E>> {
+        unsafe {
+            let jnienv = env.as_jni_env();
+            let class = self.find_class(env)?;
+            let result = (**jnienv).UnregisterNatives.unwrap()(jnienv, class);
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+            if result != JNI_OK { return Err(take_exception(env)); }
+        }
+        Ok(())
+    }
+
+    unsafe fn find_class<'env, E: ThrowableType>(&self, env: &'env Env) -> Result<jclass, Local<'env, E>> {
+        let jnienv = env.as_jni_env();
+        let class = (**jnienv).FindClass.unwrap()(jnienv, self.class.as_ptr() as *const c_char);
+        if class.is_null() { Err(take_exception(env)) } else { Ok(class) }
+    }
+}
+
+pub(crate) unsafe fn take_exception<'env, E: ThrowableType>(env: &'env Env) -> Local<'env, E> {
+    let jnienv = env.as_jni_env();
+    let exception = (**jnienv).ExceptionOccurred.unwrap()(jnienv);
+    assert!(!exception.is_null(), "JNI call failed without throwing an exception");
+    (**jnienv).ExceptionClear.unwrap()(jnienv);
+    Local::from_env_object(jnienv, exception)
+}
+
+/// Defines the hook jni-glue's JNI_OnLoad calls when the "on-load-hook" feature is enabled.  The hook is passed the
+/// loading thread's [Env], and may return anything a [native] method can - e.g. `()`, or a `Result<(), E>` where
+/// `Err(e)` is thrown and fails the library load.  Panics are likewise thrown and fail the library load.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # use jni_sys::{jboolean, jint, jobject, JNI_TRUE};
+/// # #[repr(transparent)] pub struct MainActivity(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for MainActivity {}
+/// # unsafe impl AsJValue for MainActivity { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for MainActivity { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("com/example/MainActivity\0") } }
+/// # #[repr(transparent)] pub struct Throwable(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for Throwable {}
+/// # unsafe impl AsJValue for Throwable { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for Throwable { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Throwable\0") } }
+/// # impl ThrowableType for Throwable {}
+/// # extern "system" fn on_touch(_env: &Env, _this: jobject, x: jint, y: jint) -> jboolean { JNI_TRUE }
+/// jni_glue::on_load!(register_natives);
+///
+/// fn register_natives(env: &Env) -> Result<(), Local<Throwable>> {
+///     NativeMethods::for_class::<MainActivity>()
+///         .method("onTouch", "(II)Z", on_touch as extern "system" fn(&Env, jobject, jint, jint) -> jboolean)
+///         .register(env)
+/// }
+/// # fn main() {}
+/// ```
+///
+/// [Env]:      struct.Env.html
+/// [native]:   attr.native.html
+#[cfg(feature = "on-load-hook")]
+#[macro_export] macro_rules! on_load {
+    ( $hook:expr ) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub fn __jni_glue_on_load(env: &$crate::Env) -> bool {
+            $crate::__jni_native_entry(env, || ($hook)(env));
+            !env.exception_check()
+        }
+    };
+}
+
+fn nul_terminated(string: &str) -> Vec<u8> {
+    let mut bytes = encode_modified_utf8(string).into_owned();
+    bytes.push(0);
+    bytes
+}
+
+
+
+/// Raw types that may appear in the fn pointers of [NativeMethods], and how to check them against a descriptor type.
+///
+/// | Rust Type                             | Matches                                       |
+/// | ------------------------------------- | --------------------------------------------- |
+/// | (), jbyte, jchar, jshort, jint, jlong | V, B, C, S, I, J                              |
+/// | jfloat, jdouble                       | F, D                                          |
+/// | jboolean                              | Z                                             |
+/// | jobject (jclass, jstring, ...)        | Any object or array type                      |
+/// | [Argument]\<T\>                       | T's [JniType]                                 |
+///
+/// # Safety
+///
+/// Java calls registered fn pointers directly, so implementations must be FFI safe, and ABI compatible with every
+/// descriptor type matches_descriptor accepts.  E.g. accepting "J" for a 32-bit type would let Java pass arguments the
+/// fn pointer misreads.
+///
+/// [NativeMethods]:    struct.NativeMethods.html
+/// [Argument]:         struct.Argument.html
+/// [JniType]:          trait.JniType.html
+pub unsafe trait NativeFnType {
+    fn matches_descriptor(ty: &str) -> bool;
+}
+
+/// Raw types that may receive the `this` object (or `jclass`, for static methods) of [NativeMethods] fn pointers:
+/// jobject (and thus jclass), or [Argument]\<T\>.  Unlike other parameters, these aren't described by the method
+/// descriptor, so there's nothing to check them against.
+///
+/// # Safety
+///
+/// Implementations must be FFI safe, and ABI compatible with a (never null) jobject.
+///
+/// [NativeMethods]:    struct.NativeMethods.html
+/// [Argument]:         struct.Argument.html
+pub unsafe trait NativeThis {}
+
+/// `extern "system" fn(env, this_or_class, args...) -> ret` pointers that can be registered with [NativeMethods].
+/// `env` may be either a `&Env` or a `*mut JNIEnv`.
+///
+/// # Safety
+///
+/// as_ptr's result is registered with RegisterNatives, so implementations must be `extern "system"` fn pointers taking
+/// the JNIEnv, then the this object or jclass, then the parameters described by any descriptor check_descriptor
+/// accepts - in order, each ABI compatible with the corresponding JNI type - and returning a type ABI compatible with
+/// the descriptor's return type.
+///
+/// [NativeMethods]:    struct.NativeMethods.html
+pub unsafe trait NativeFn : Copy {
+    /// Checks that `descriptor` is a valid method descriptor matching this fn pointer's types.
+    fn check_descriptor(descriptor: &str) -> Result<(), String>;
+    fn as_ptr(self) -> *mut c_void;
+}
+
+macro_rules! native_fn_type_via_jni_type {
+    ( $($ty:ty),* $(,)? ) => {$(
+        unsafe impl NativeFnType for $ty { fn matches_descriptor(ty: &str) -> bool { <$ty as JniType>::static_with_jni_type(|t| t.trim_end_matches('\0') == ty) } }
+    )*};
+}
+
+native_fn_type_via_jni_type!((), jbyte, jchar, jshort, jint, jlong, jfloat, jdouble);
+
+unsafe impl NativeFnType for jboolean       { fn matches_descriptor(ty: &str) -> bool { ty == "Z" } }
+unsafe impl NativeFnType for jni_sys::jchar { fn matches_descriptor(ty: &str) -> bool { ty == "C" } }
+unsafe impl NativeFnType for jobject        { fn matches_descriptor(ty: &str) -> bool { ty.starts_with('L') || ty.starts_with('[') } }
+
+unsafe impl NativeThis for jobject {}
+unsafe impl<T: AsValidJObjectAndEnv> NativeThis for Argument<T> {}
+
+unsafe impl<T: AsValidJObjectAndEnv + JniType> NativeFnType for Argument<T> {
+    fn matches_descriptor(ty: &str) -> bool {
+        T::static_with_jni_type(|t| {
+            let t = t.trim_end_matches('\0');
+            if t.starts_with('[') { ty == t } else { ty.len() == t.len() + 2 && ty.starts_with('L') && ty.ends_with(';') && &ty[1..ty.len()-1] == t }
+        })
+    }
+}
+
+macro_rules! native_fn {
+    ( $($arg:ident),* ) => {
+        unsafe impl<This: NativeThis, R: NativeFnType $(, $arg: NativeFnType)*> NativeFn for extern "system" fn(&Env, This $(, $arg)*) -> R {
+            fn check_descriptor(descriptor: &str) -> Result<(), String> { check_descriptor(descriptor, &[$($arg::matches_descriptor),*], R::matches_descriptor) }
+            fn as_ptr(self) -> *mut c_void { self as *mut c_void }
+        }
+
+        unsafe impl<This: NativeThis, R: NativeFnType $(, $arg: NativeFnType)*> NativeFn for extern "system" fn(*mut JNIEnv, This $(, $arg)*) -> R {
+            fn check_descriptor(descriptor: &str) -> Result<(), String> { check_descriptor(descriptor, &[$($arg::matches_descriptor),*], R::matches_descriptor) }
+            fn as_ptr(self) -> *mut c_void { self as *mut c_void }
+        }
+    };
+}
+
+native_fn!();
+native_fn!(A0);
+native_fn!(A0, A1);
+native_fn!(A0, A1, A2);
+native_fn!(A0, A1, A2, A3);
+native_fn!(A0, A1, A2, A3, A4);
+native_fn!(A0, A1, A2, A3, A4, A5);
+native_fn!(A0, A1, A2, A3, A4, A5, A6);
+native_fn!(A0, A1, A2, A3, A4, A5, A6, A7);
+
+fn check_descriptor(descriptor: &str, args: &[fn(&str) -> bool], ret: fn(&str) -> bool) -> Result<(), String> {
+    let (params, return_type) = split_descriptor(descriptor).ok_or_else(|| "invalid method descriptor".to_owned())?;
+    if params.len() != args.len() {
+        return Err(format!("descriptor has {} parameter(s), but the fn pointer has {}", params.len(), args.len()));
+    }
+    for (idx, (param, matches)) in params.iter().zip(args.iter()).enumerate() {
+        if !matches(param) { return Err(format!("parameter {} ({}) doesn't match the fn pointer's parameter type", idx, param)); }
+    }
+    if !ret(return_type) { return Err(format!("return type ({}) doesn't match the fn pointer's return type", return_type)); }
+    Ok(())
+}
+
+/// Splits e.g. `"(I[Ljava/lang/String;)Z"` into `(["I", "[Ljava/lang/String;"], "Z")`
+fn split_descriptor(descriptor: &str) -> Option<(Vec<&str>, &str)> {
+    fn type_len(ty: &str) -> Option<usize> {
+        let dims = ty.bytes().take_while(|&b| b == b'[').count();
+        match ty.as_bytes().get(dims)? {
+            b'Z' | b'B' | b'C' | b'S' | b'I' | b'J' | b'F' | b'D' => Some(dims + 1),
+            b'V' if dims == 0 => Some(1),
+            b'L' => Some(dims + ty[dims..].find(';')? + 1),
+            _ => None,
+        }
+    }
+
+    if !descriptor.starts_with('(') { return None; }
+    let end = descriptor.find(')')?;
+    let mut remaining = &descriptor[1..end];
+    let return_type = &descriptor[end+1..];
+
+    let mut params = Vec::new();
+    while !remaining.is_empty() {
+        let len = type_len(remaining)?;
+        if remaining.starts_with('V') { return None; }
+        params.push(&remaining[..len]);
+        remaining = &remaining[len..];
+    }
+    if type_len(return_type)? != return_type.len() { return None; }
+    Some((params, return_type))
+}
+
+#[test] fn native_fn_check_descriptor() {
+    type OnTouch = extern "system" fn(&Env, jobject, jint, jint) -> jboolean;
+    assert_eq!(OnTouch::check_descriptor("(II)Z"), Ok(()));
+    assert!(OnTouch::check_descriptor("(IJ)Z").is_err());
+    assert!(OnTouch::check_descriptor("(II)V").is_err());
+    assert!(OnTouch::check_descriptor("(I)Z").is_err());
+    assert!(OnTouch::check_descriptor("(II").is_err());
+
+    type Raw = extern "system" fn(*mut JNIEnv, jclass, jobject, Argument<IntArray>);
+    assert_eq!(Raw::check_descriptor("([[Ljava/lang/String;[I)V"), Ok(()));
+    assert!(Raw::check_descriptor("(I[I)V").is_err());
+    assert!(Raw::check_descriptor("(Ljava/lang/String;[J)V").is_err());
+
+    type Typed = extern "system" fn(&Env, Argument<IntArray>) -> jint; // `this` isn't part of the descriptor
+    assert_eq!(Typed::check_descriptor("()I"), Ok(()));
+
+    assert_eq!(split_descriptor("(I[Ljava/lang/String;[[C)V"), Some((vec!["I", "[Ljava/lang/String;", "[[C"], "V")));
+    assert_eq!(split_descriptor("(V)V"), None);
+    assert_eq!(split_descriptor("()[V"), None);
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn native_methods_register_failure() {
+    // RegisterNatives failures should be returned, rather than left pending.
+    extern "system" fn not_a_method(_env: &Env, _this: jobject) -> jint { 0 }
+    with_test_env(|env|{
+        let missing = NativeMethods::for_class::<TestThrowable>().method("notAMethod", "()I", not_a_method as extern "system" fn(&Env, jobject) -> jint);
+        let error = JavaException::from(missing.register::<TestThrowable>(env).unwrap_err());
+        assert_eq!(error.class_name(), "java.lang.NoSuchMethodError");
+    });
+}
@@ -0,0 +1,411 @@
+use super::*;
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+
+
+/// Implements Java interfaces from Rust, via [java.lang.reflect.Proxy](https://docs.oracle.com/javase/8/docs/api/java/lang/reflect/Proxy.html)
+/// instances backed by an [InvocationHandler].
+///
+/// The Rust handler is freed after Java garbage collects the proxy, by a java.lang.ref.Cleaner where available (Java 9+,
+/// Android 13+) or by finalization otherwise.  Until then - or forever, if the proxy is never collected before the VM
+/// exits, or on a VM with neither Cleaners nor finalization enabled - the handler is leaked.  Calls to `equals`,
+/// `hashCode`, and `toString` are handled on the Java side, using the proxy's identity.
+///
+/// The handler is implemented by a small Java class embedded in jni-glue, which is defined at runtime via DefineClass
+/// if it can't already be found.  Android doesn't support DefineClass, so there you'll want to include
+/// `jni-glue/java/com/maulingmonkey/jni_glue/RustInvocationHandler.java` in your app's sources instead.
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # #[repr(transparent)] pub struct Runnable(ObjectAndEnv);
+/// # unsafe impl AsValidJObjectAndEnv for Runnable {}
+/// # unsafe impl AsJValue for Runnable { fn as_jvalue(&self) -> jni_sys::jvalue { jni_sys::jvalue { l: self.0.object } } }
+/// # unsafe impl JniType for Runnable { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Runnable\0") } }
+/// # fn example<Throwable: ThrowableType>(env: &Env) -> Result<(), Local<Throwable>> {
+/// let runnable = Proxy::create::<Runnable, Throwable>(env, |invocation: &Invocation| {
+///     match invocation.method_name().as_str() {
+///         "run"   => invocation.result(println!("Hello from Rust!")),
+///         _       => invocation.unsupported(),
+///     }
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [InvocationHandler]:    trait.InvocationHandler.html
+pub struct Proxy(());
+
+/// Handles method invocations on a [Proxy].  Implemented for closures taking an [Invocation].
+///
+/// [Proxy]:        struct.Proxy.html
+/// [Invocation]:   struct.Invocation.html
+pub trait InvocationHandler : Send + Sync + 'static {
+    fn invoke(&self, invocation: &Invocation) -> InvocationResult;
+}
+
+/// A method invocation on a [Proxy]:  which method was called, and with what arguments.
+///
+/// [Proxy]:    struct.Proxy.html
+pub struct Invocation<'env> {
+    env:    &'env Env,
+    proxy:  jobject,
+    method: jobject,
+    args:   jobjectArray,
+}
+
+/// The (boxed) value to return from a [Proxy] method, created by [Invocation::result].
+///
+/// [Proxy]:                struct.Proxy.html
+/// [Invocation::result]:   struct.Invocation.html#method.result
+pub struct InvocationResult(jobject);
+
+/// Argument types of [Proxy] methods, and how to convert them from the objects (or boxed primitives) Java passes.
+///
+/// # Safety
+///
+/// from_proxy_arg must be sound for any `arg` that's null or a valid local reference to an object of the (boxed) type
+/// Java declared for the parameter, with `env` being the current thread's [Env].  It mustn't delete `arg`, which Java
+/// cleans up when the invocation returns - nor let the result outlive `'env`.
+///
+/// [Env]:      struct.Env.html
+/// [Proxy]:    struct.Proxy.html
+pub unsafe trait ProxyArg<'env> : Sized {
+    /// Converts the object (or boxed primitive) Java passed for this argument.
+    ///
+    /// # Safety
+    ///
+    /// `env` must be the current thread's [Env], and `arg` must be null or a valid local reference to an object of the
+    /// (boxed) type Java declared for the parameter, which outlives `'env`.
+    ///
+    /// [Env]:  struct.Env.html
+    unsafe fn from_proxy_arg(env: &'env Env, arg: jobject) -> Self;
+}
+
+/// Return types of [Proxy] methods, and how to convert them into the objects (or boxed primitives) Java expects.
+///
+/// # Safety
+///
+/// into_proxy_return must return null or a valid local reference, which Java takes ownership of - to an object of the
+/// (boxed) type Java declared for the method's return value.  Returning an object of the wrong type makes Java throw a
+/// ClassCastException, but returning a dangling or already deleted reference is undefined behavior.
+///
+/// [Proxy]:    struct.Proxy.html
+pub unsafe trait ProxyReturn {
+    fn into_proxy_return(self, env: &Env) -> jobject;
+}
+
+impl Proxy {
+    /// Uses java.lang.reflect.Proxy.newProxyInstance to implement the interface `I` by forwarding all calls to
+    /// `handler`, returning the thrown exception if `I` isn't an interface (or couldn't be found.)
+    pub fn create<'env, I: AsValidJObjectAndEnv + JniType, E: ThrowableType>(env: &'env Env, handler: impl InvocationHandler) -> Result<Local<'env, I>, Local<'env, E>> {
+        unsafe {
+            let jnienv = env.as_jni_env();
+
+            let handler_class = handler_class(env)?;
+            let handler_ctor = env.require_method(handler_class, "<init>\0", "(J)V\0");
+            let handler_ptr = Box::into_raw(Box::new(Box::new(handler) as Box<dyn InvocationHandler>));
+            let args = [jvalue { j: handler_ptr as jlong }];
+            let handler = (**jnienv).NewObjectA.unwrap()(jnienv, handler_class, handler_ctor, args.as_ptr());
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, handler_class);
+            if handler.is_null() {
+                drop(Box::from_raw(handler_ptr));
+                return Err(take_exception(env));
+            }
+
+            let interface = I::static_with_jni_type(|ty| {
+                let mut ty = ty.trim_end_matches('\0').to_owned();
+                ty.push('\0');
+                (**jnienv).FindClass.unwrap()(jnienv, ty.as_ptr() as *const c_char)
+            });
+            if interface.is_null() { return Err(take_exception(env)); }
+
+            let (class_class, get_class_loader) = env.require_class_method("java/lang/Class\0", "getClassLoader\0", "()Ljava/lang/ClassLoader;\0");
+            let loader = (**jnienv).CallObjectMethodA.unwrap()(jnienv, interface, get_class_loader, null());
+            if env.exception_check() { return Err(take_exception(env)); }
+            let interfaces = (**jnienv).NewObjectArray.unwrap()(jnienv, 1, class_class, interface);
+            if interfaces.is_null() { return Err(take_exception(env)); }
+
+            let (proxy_class, new_proxy_instance) = env.require_class_static_method("java/lang/reflect/Proxy\0", "newProxyInstance\0", "(Ljava/lang/ClassLoader;[Ljava/lang/Class;Ljava/lang/reflect/InvocationHandler;)Ljava/lang/Object;\0");
+            let args = [jvalue { l: loader }, jvalue { l: interfaces }, jvalue { l: handler }];
+            let proxy = (**jnienv).CallStaticObjectMethodA.unwrap()(jnienv, proxy_class, new_proxy_instance, args.as_ptr());
+            let thrown = env.exception_check();
+
+            for local in [proxy_class, interfaces, loader, class_class, interface, handler].iter() {
+                (**jnienv).DeleteLocalRef.unwrap()(jnienv, *local);
+            }
+            if thrown { return Err(take_exception(env)); }
+            Ok(Local::from_env_object(jnienv, proxy))
+        }
+    }
+}
+
+impl<F: Fn(&Invocation) -> InvocationResult + Send + Sync + 'static> InvocationHandler for F {
+    fn invoke(&self, invocation: &Invocation) -> InvocationResult { self(invocation) }
+}
+
+impl<'env> Invocation<'env> {
+    pub fn env(&self) -> &'env Env { self.env }
+
+    /// The proxy object the method was invoked on.
+    pub fn proxy(&self) -> jobject { self.proxy }
+
+    /// The java.lang.reflect.Method that was invoked.
+    pub fn method(&self) -> jobject { self.method }
+
+    /// The name of the invoked method, e.g. `"onClick"`.
+    pub fn method_name(&self) -> String {
+        unsafe { call_string_method(self.env, self.method, "java/lang/reflect/Method\0", "getName\0") }
+    }
+
+    /// The descriptor of the invoked method, e.g. `"(Landroid/view/View;)V"` - useful for telling overloads apart.
+    pub fn method_descriptor(&self) -> String {
+        unsafe {
+            let env = self.env;
+            let jnienv = env.as_jni_env();
+            let (class, get_parameter_types) = env.require_class_method("java/lang/reflect/Method\0", "getParameterTypes\0", "()[Ljava/lang/Class;\0");
+            let get_return_type = env.require_method(class, "getReturnType\0", "()Ljava/lang/Class;\0");
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+
+            let mut descriptor = String::from("(");
+            let parameter_types = (**jnienv).CallObjectMethodA.unwrap()(jnienv, self.method, get_parameter_types, null());
+            assert!(!env.exception_check() && !parameter_types.is_null());
+            for i in 0..(**jnienv).GetArrayLength.unwrap()(jnienv, parameter_types) {
+                let parameter_type = (**jnienv).GetObjectArrayElement.unwrap()(jnienv, parameter_types, i);
+                descriptor.push_str(&class_descriptor(env, parameter_type));
+                (**jnienv).DeleteLocalRef.unwrap()(jnienv, parameter_type);
+            }
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, parameter_types);
+            descriptor.push(')');
+
+            let return_type = (**jnienv).CallObjectMethodA.unwrap()(jnienv, self.method, get_return_type, null());
+            assert!(!env.exception_check() && !return_type.is_null());
+            descriptor.push_str(&class_descriptor(env, return_type));
+            (**jnienv).DeleteLocalRef.unwrap()(jnienv, return_type);
+            descriptor
+        }
+    }
+
+    /// The number of arguments the method was invoked with.
+    pub fn arg_count(&self) -> usize {
+        if self.args.is_null() { return 0; } // Java passes null instead of an empty array
+        let jnienv = self.env.as_jni_env();
+        unsafe { (**jnienv).GetArrayLength.unwrap()(jnienv, self.args) as usize }
+    }
+
+    /// Gets an argument, unboxing primitives.
+    ///
+    /// **Panics** if `index` is out of bounds, or if `T` is a primitive but the argument isn't the matching box type.
+    pub fn arg<T: ProxyArg<'env>>(&self, index: usize) -> T {
+        assert!(index < self.arg_count(), "Invocation::arg({}) out of bounds", index);
+        let jnienv = self.env.as_jni_env();
+        unsafe {
+            let arg = (**jnienv).GetObjectArrayElement.unwrap()(jnienv, self.args, index as jsize);
+            T::from_proxy_arg(self.env, arg) // arg is left for Java to clean up when the invocation returns
+        }
+    }
+
+    /// Converts `value` into the method's return value, boxing primitives.  Return `()` from `void` methods.
+    pub fn result<R: ProxyReturn>(&self, value: R) -> InvocationResult {
+        InvocationResult(value.into_proxy_return(self.env))
+    }
+
+    /// Throws java.lang.UnsupportedOperationException, for methods the handler doesn't implement.
+    pub fn unsupported(&self) -> InvocationResult {
+        self.env.throw_new::<UnsupportedOperationException>(&format!("{} isn't implemented by this Rust proxy", self.method_name()));
+        InvocationResult(null_mut())
+    }
+}
+
+pub(crate) struct UnsupportedOperationException;
+unsafe impl JniType for UnsupportedOperationException { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/UnsupportedOperationException\0") } }
+
+/// The JVM descriptor of a java.lang.Class, e.g. `"I"`, `"Ljava/lang/String;"`, or `"[Ljava/lang/String;"`.
+unsafe fn class_descriptor(env: &Env, class: jclass) -> String {
+    let name = call_string_method(env, class, "java/lang/Class\0", "getName\0");
+    match name.as_str() {
+        "void"      => "V".to_owned(),
+        "boolean"   => "Z".to_owned(),
+        "byte"      => "B".to_owned(),
+        "char"      => "C".to_owned(),
+        "short"     => "S".to_owned(),
+        "int"       => "I".to_owned(),
+        "long"      => "J".to_owned(),
+        "float"     => "F".to_owned(),
+        "double"    => "D".to_owned(),
+        array if array.starts_with('[') => array.replace('.', "/"),
+        class => format!("L{};", class.replace('.', "/")),
+    }
+}
+
+unsafe fn call_string_method(env: &Env, object: jobject, class: &str, method: &str) -> String {
+    let jnienv = env.as_jni_env();
+    let (class, method) = env.require_class_method(class, method, "()Ljava/lang/String;\0");
+    (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+    let string = (**jnienv).CallObjectMethodA.unwrap()(jnienv, object, method, null());
+    assert!(!env.exception_check() && !string.is_null());
+    let result = StringChars::from_env_jstring(env, string).to_string_lossy();
+    (**jnienv).DeleteLocalRef.unwrap()(jnienv, string);
+    result
+}
+
+
+
+macro_rules! proxy_primitives {
+    ( $( $ty:ty => $box:expr, $value:expr, $value_sig:expr, $value_of_sig:expr, $call:ident, $field:ident, $from:expr, $into:expr; )* ) => {$(
+        unsafe impl<'env> ProxyArg<'env> for $ty {
+            unsafe fn from_proxy_arg(env: &'env Env, arg: jobject) -> Self {
+                let jnienv = env.as_jni_env();
+                let (class, method) = env.require_class_method($box, $value, $value_sig);
+                assert!((**jnienv).IsInstanceOf.unwrap()(jnienv, arg, class) != JNI_FALSE, "Invocation::arg: expected a non-null {}", $box.trim_end_matches('\0'));
+                (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+                let value = (**jnienv).$call.unwrap()(jnienv, arg, method, null());
+                assert!(!env.exception_check()); // Unboxing shouldn't throw
+                ($from)(value)
+            }
+        }
+
+        unsafe impl ProxyReturn for $ty {
+            fn into_proxy_return(self, env: &Env) -> jobject {
+                unsafe {
+                    let jnienv = env.as_jni_env();
+                    let (class, value_of) = env.require_class_static_method($box, "valueOf\0", $value_of_sig);
+                    let args = [jvalue { $field: ($into)(self) }];
+                    let boxed = (**jnienv).CallStaticObjectMethodA.unwrap()(jnienv, class, value_of, args.as_ptr());
+                    (**jnienv).DeleteLocalRef.unwrap()(jnienv, class);
+                    boxed
+                }
+            }
+        }
+    )*};
+}
+
+proxy_primitives! {
+    bool    => "java/lang/Boolean\0",   "booleanValue\0",   "()Z\0", "(Z)Ljava/lang/Boolean;\0",    CallBooleanMethodA, z, |v| v != JNI_FALSE,  |v| if v { JNI_TRUE } else { JNI_FALSE };
+    jbyte   => "java/lang/Byte\0",      "byteValue\0",      "()B\0", "(B)Ljava/lang/Byte;\0",       CallByteMethodA,    b, |v| v,               |v| v;
+    jchar   => "java/lang/Character\0", "charValue\0",      "()C\0", "(C)Ljava/lang/Character;\0",  CallCharMethodA,    c, jchar,               |v: jchar| v.0;
+    jshort  => "java/lang/Short\0",     "shortValue\0",     "()S\0", "(S)Ljava/lang/Short;\0",      CallShortMethodA,   s, |v| v,               |v| v;
+    jint    => "java/lang/Integer\0",   "intValue\0",       "()I\0", "(I)Ljava/lang/Integer;\0",    CallIntMethodA,     i, |v| v,               |v| v;
+    jlong   => "java/lang/Long\0",      "longValue\0",      "()J\0", "(J)Ljava/lang/Long;\0",       CallLongMethodA,    j, |v| v,               |v| v;
+    jfloat  => "java/lang/Float\0",     "floatValue\0",     "()F\0", "(F)Ljava/lang/Float;\0",      CallFloatMethodA,   f, |v| v,               |v| v;
+    jdouble => "java/lang/Double\0",    "doubleValue\0",    "()D\0", "(D)Ljava/lang/Double;\0",     CallDoubleMethodA,  d, |v| v,               |v| v;
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> ProxyArg<'env> for Option<Ref<'env, T>> {
+    unsafe fn from_proxy_arg(env: &'env Env, arg: jobject) -> Self { NativeArg::from_raw(env, arg) }
+}
+
+unsafe impl ProxyReturn for () {
+    fn into_proxy_return(self, _env: &Env) -> jobject { null_mut() }
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> ProxyReturn for Local<'env, T> {
+    fn into_proxy_return(self, _env: &Env) -> jobject { Local::leak(self).oae.object } // Java takes ownership of returned local refs
+}
+
+unsafe impl<'env, T: AsValidJObjectAndEnv> ProxyReturn for Option<Local<'env, T>> {
+    fn into_proxy_return(self, env: &Env) -> jobject { self.map_or(null_mut(), |local| local.into_proxy_return(env)) }
+}
+
+unsafe impl<T: ProxyReturn, E: NativeError> ProxyReturn for Result<T, E> {
+    fn into_proxy_return(self, env: &Env) -> jobject {
+        match self {
+            Ok(value)   => value.into_proxy_return(env),
+            Err(error)  => { error.throw(env); null_mut() },
+        }
+    }
+}
+
+
+
+struct RustInvocationHandler;
+unsafe impl JniType for RustInvocationHandler { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("com/maulingmonkey/jni_glue/RustInvocationHandler\0") } }
+
+#[repr(transparent)] struct Class(ObjectAndEnv); // java.lang.Class
+unsafe impl AsValidJObjectAndEnv for Class {}
+unsafe impl AsJValue for Class { fn as_jvalue(&self) -> jvalue { jvalue { l: self.0.object } } }
+unsafe impl JniType for Class { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Class\0") } }
+
+/// Built from jni-glue/java/com/maulingmonkey/jni_glue/RustInvocationHandler.java via:
+/// `javac --release 8 -g:none -d . com/maulingmonkey/jni_glue/RustInvocationHandler.java`
+static HANDLER_CLASS : IncludedClass = crate::include_class!("com/maulingmonkey/jni_glue/RustInvocationHandler", "../java/com/maulingmonkey/jni_glue/RustInvocationHandler.class");
+
+lazy_static! { // Mutex::new is not const
+    /// The VMs RustInvocationHandler's natives have been registered with.
+    static ref REGISTERED : Mutex<HashSet<GenVM>> = Mutex::new(HashSet::new());
+}
+
+/// Forgets that RustInvocationHandler's natives were registered with `gen_vm`, which has been unloaded.
+pub(crate) fn forget_vm(gen_vm: GenVM) {
+    REGISTERED.lock().unwrap().remove(&gen_vm);
+}
+
+/// Finds - or failing that, defines - RustInvocationHandler, with natives registered.
+unsafe fn handler_class<'env, E: ThrowableType>(env: &'env Env) -> Result<jclass, Local<'env, E>> {
+    let class = HANDLER_CLASS.get::<Class, E>(env)?;
+    let gen_vm = env.get_gen_vm();
+    if !REGISTERED.lock().unwrap().contains(&gen_vm) {
+        // Not locked while registering:  racing threads registering the same natives twice is harmless.
+        handler_natives().register(env)?;
+        REGISTERED.lock().unwrap().insert(gen_vm);
+    }
+    Ok(Local::leak(class).oae.object)
+}
+
+fn handler_natives() -> NativeMethods {
+    NativeMethods::for_class::<RustInvocationHandler>()
+        .method("invoke", "(JLjava/lang/Object;Ljava/lang/reflect/Method;[Ljava/lang/Object;)Ljava/lang/Object;", handler_invoke as extern "system" fn(&Env, jclass, jlong, jobject, jobject, jobject) -> jobject)
+        .method("drop", "(J)V", handler_drop as extern "system" fn(&Env, jclass, jlong))
+}
+
+extern "system" fn handler_invoke(env: &Env, _class: jclass, handler: jlong, proxy: jobject, method: jobject, args: jobject) -> jobject {
+    __jni_native_entry(env, || {
+        let handler = unsafe { &*(handler as *const Box<dyn InvocationHandler>) };
+        handler.invoke(&Invocation { env, proxy, method, args }).0
+    })
+}
+
+extern "system" fn handler_drop(env: &Env, _class: jclass, handler: jlong) {
+    __jni_native_entry(env, || drop(unsafe { Box::from_raw(handler as *mut Box<dyn InvocationHandler>) }))
+}
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn proxy_int_unary_operator() {
+    // Rust closures should be able to implement Java interfaces, and be freed when Java is done with them.
+    with_test_env(|env|{
+        let state = std::sync::Arc::new(std::sync::atomic::AtomicI32::new(0));
+        let handler_state = state.clone();
+        let operator = Proxy::create::<TestIntUnaryOperator, TestThrowable>(env, move |invocation: &Invocation| {
+            match invocation.method_name().as_str() {
+                "applyAsInt" => {
+                    assert_eq!(invocation.method_descriptor(), "(I)I");
+                    handler_state.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    invocation.result(invocation.arg::<jint>(0) * 2)
+                },
+                _ => invocation.unsupported(),
+            }
+        }).unwrap();
+        unsafe {
+            let (class, apply_as_int) = env.require_class_method("java/util/function/IntUnaryOperator\0", "applyAsInt\0", "(I)I\0");
+            let and_then = env.require_method(class, "andThen\0", "(Ljava/util/function/IntUnaryOperator;)Ljava/util/function/IntUnaryOperator;\0");
+            let to_string = env.require_method(class, "toString\0", "()Ljava/lang/String;\0");
+            assert_eq!(env.call_int_method_a::<TestThrowable>(operator.0.object, apply_as_int, [jvalue { i: 21 }].as_ptr()).unwrap(), 42);
+            assert!(StringChars::from_env_jstring(env, env.call_object_method_a::<TestThrowable, TestThrowable>(operator.0.object, to_string, null()).unwrap().unwrap().0.object).to_string_lossy().starts_with("RustProxy@"));
+            let error = JavaException::from(env.call_object_method_a::<TestThrowable, TestThrowable>(operator.0.object, and_then, [jvalue { l: null_mut() }].as_ptr()).unwrap_err());
+            assert_eq!(error.to_string(), "java.lang.UnsupportedOperationException: andThen isn't implemented by this Rust proxy");
+        }
+        assert_eq!(state.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(Proxy::create::<TestThrowable, TestThrowable>(env, |i: &Invocation| i.unsupported()).is_err()); // Not an interface
+        drop(operator);
+
+        let (system, gc) = unsafe { env.require_class_static_method("java/lang/System\0", "gc\0", "()V\0") };
+        let run_finalization = unsafe { env.require_static_method(system, "runFinalization\0", "()V\0") };
+        for _ in 0..100 {
+            if std::sync::Arc::strong_count(&state) == 1 { break; }
+            unsafe { env.call_static_void_method_a::<TestThrowable>(system, gc, null()).unwrap() };
+            unsafe { env.call_static_void_method_a::<TestThrowable>(system, run_finalization, null()).unwrap() };
+            std::thread::sleep(std::time::Duration::from_millis(10)); // Cleaners run on their own thread
+        }
+        assert_eq!(std::sync::Arc::strong_count(&state), 1);
+    });
+}
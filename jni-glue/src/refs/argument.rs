@@ -45,14 +45,7 @@ impl<Class: AsValidJObjectAndEnv> Argument<Class> {
         if self.object.is_null() {
             None
         } else {
-            let jnienv = env.as_jni_env();
-            let gen_vm = env.get_gen_vm();
-            let global = (**jnienv).NewGlobalRef.unwrap()(jnienv, self.object);
-            Some(Global {
-                global,
-                gen_vm,
-                pd: PhantomData,
-            })
+            Some(Global::from_env_object(env, self.object))
         }
     }
 }
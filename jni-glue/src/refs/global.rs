@@ -8,6 +8,8 @@ use super::*;
 /// Unlike Local, this can be stored statically and shared between threads.  This has a few caveats:
 /// * You must create a [GlobalRef] before use.
 /// * The [Global] can be invalidated if the [VM] is unloaded.
+/// * Dropping a [Global] after its [VM] has been unloaded (e.g. after JNI_OnUnload, or an [OwnedVM] was dropped) is a
+///   no-op, rather than a panic:  the VM's references went with it, so there's nothing left to DeleteGlobalRef.
 /// 
 /// **Not FFI Safe:**  #\[repr(rust)\], and exact layout is likely to change - depending on exact features used - in the
 /// future.  Specifically, on Android, since we're guaranteed to only have a single ambient [VM], we can likely store the
@@ -17,6 +19,7 @@ use super::*;
 /// [VM]:           struct.VM.html
 /// [Global]:       struct.Global.html
 /// [GlobalRef]:    type.GlobalRef.html
+/// [OwnedVM]:      struct.OwnedVM.html
 pub struct Global<Class: AsValidJObjectAndEnv> {
     pub(crate) global:  jobject,
     pub(crate) gen_vm:  GenVM,
@@ -27,6 +30,28 @@ unsafe impl<Class: AsValidJObjectAndEnv> Send for Global<Class> {}
 unsafe impl<Class: AsValidJObjectAndEnv> Sync for Global<Class> {}
 
 impl<Class: AsValidJObjectAndEnv> Global<Class> {
+    /// Uses env.NewGlobalRef to create a new [Global] referencing the same object as `r`.  `r` may be any kind of
+    /// reference - a [Local], an array element, another [Global]'s [GlobalRef], etc.
+    ///
+    /// [Global]:       struct.Global.html
+    /// [Local]:        struct.Local.html
+    /// [GlobalRef]:    type.GlobalRef.html
+    pub fn new<'env>(r: &Ref<'env, Class>) -> Self {
+        unsafe { Self::from_env_object(Env::from_ptr(r.oae.env), r.oae.object) }
+    }
+
+    pub(crate) unsafe fn from_env_object(env: &Env, object: jobject) -> Self {
+        let jnienv = env.as_jni_env();
+        let gen_vm = env.get_gen_vm();
+        let global = (**jnienv).NewGlobalRef.unwrap()(jnienv, object);
+        assert!(!global.is_null()); // Only sane failure here is an OOM
+        Global {
+            global,
+            gen_vm,
+            pd: PhantomData,
+        }
+    }
+
     pub fn with<'env>(&'env self, env: &'env Env) -> GlobalRef<'env, Class> {
         assert_eq!(self.gen_vm, env.get_gen_vm()); // Soundness check - env *must* belong to the same VM!
         unsafe { self.with_unchecked(env) }
@@ -47,25 +72,28 @@ impl<Class: AsValidJObjectAndEnv> Global<Class> {
 
 impl<'env, Class: AsValidJObjectAndEnv> From<Local<'env, Class>> for Global<Class> {
     fn from(local: Local<'env, Class>) -> Global<Class> {
-        let env = unsafe { Env::from_ptr(local.oae.env) };
-        let jnienv = env.as_jni_env();
-        let gen_vm = env.get_gen_vm();
-        let global = unsafe { (**jnienv).NewGlobalRef.unwrap()(jnienv, local.oae.object) };
-        Global {
-            global,
-            gen_vm,
-            pd: PhantomData,
-        }
+        unsafe { Global::from_env_object(Env::from_ptr(local.oae.env), local.oae.object) }
+    }
+}
+
+impl<Class: AsValidJObjectAndEnv> Clone for Global<Class> {
+    fn clone(&self) -> Self {
+        VMS.read().unwrap().use_vm(self.gen_vm, |vm|{
+            let attach = vm.attach();
+            unsafe { Global::from_env_object(attach.env(), self.global) }
+        })
     }
 }
 
 impl<Class: AsValidJObjectAndEnv> Drop for Global<Class> {
+    /// Uses env.DeleteGlobalRef, attaching the current thread for the duration if necessary.  Does nothing if the
+    /// [VM](struct.VM.html) this was created with has since been unloaded.
     fn drop(&mut self) {
-        VMS.read().unwrap().use_vm(self.gen_vm, |vm|{
-            vm.with_env(|env|{
-                let env = env.as_jni_env();
-                unsafe { (**env).DeleteGlobalRef.unwrap()(env, self.global); }
-            });
+        // If the VM has since been unloaded, so have all of its references - there's nothing left to delete.
+        VMS.read().unwrap().try_use_vm(self.gen_vm, |vm|{
+            let attach = vm.attach(); // Don't leave threads that merely drop a reference attached forever.
+            let env = attach.env().as_jni_env();
+            unsafe { (**env).DeleteGlobalRef.unwrap()(env, self.global); }
         });
     }
 }
@@ -33,7 +33,6 @@ pub struct Local<'env, Class: AsValidJObjectAndEnv> {
     pub(crate) _class: PhantomData<&'env Class>,
 }
 
-// Could implement clone if necessary via NewLocalRef
 // Do *not* implement Copy, cannot be safely done.
 
 impl<'env, Class: AsValidJObjectAndEnv> Local<'env, Class> {
@@ -45,6 +44,21 @@ impl<'env, Class: AsValidJObjectAndEnv> Local<'env, Class> {
         }
     }
 
+    /// Uses env.NewLocalRef to create a new [Local] referencing the same object as `r`.  `r` may be any kind of
+    /// reference - another [Local], an array element, a [Global]'s [GlobalRef], etc.
+    ///
+    /// [Local]:        struct.Local.html
+    /// [Global]:       struct.Global.html
+    /// [GlobalRef]:    type.GlobalRef.html
+    pub fn from_ref(r: &Ref<'env, Class>) -> Self {
+        let env = r.oae.env as *mut JNIEnv;
+        unsafe {
+            let object = (**env).NewLocalRef.unwrap()(env, r.oae.object);
+            assert!(!object.is_null()); // Only sane failure here is an OOM
+            Self::from_env_object(env, object)
+        }
+    }
+
     pub fn leak(local: Self) -> Ref<'env, Class> {
         let result = Ref {
             oae: ObjectAndEnv {
@@ -57,6 +71,33 @@ impl<'env, Class: AsValidJObjectAndEnv> Local<'env, Class> {
         std::mem::forget(local); // Don't allow local to DeleteLocalRef the jobject
         result
     }
+
+    /// Uses env.MonitorEnter to enter the object's monitor, like a Java `synchronized (object) { ... }` block.  The
+    /// monitor is exited when the returned [MonitorGuard] is dropped.  Required for calling e.g. wait/notify.
+    ///
+    /// [MonitorGuard]: struct.MonitorGuard.html
+    pub fn synchronized<E: ThrowableType>(&self) -> Result<MonitorGuard<'_>, Local<'env, E>> {
+        unsafe { MonitorGuard::enter(self.oae.env as *mut JNIEnv, self.oae.object) }
+    }
+
+    /// Uses env.IsInstanceOf to check if the object is a `T`, with `T`'s class looked up once per [VM].
+    ///
+    /// [VM]:   struct.VM.html
+    pub(crate) fn is_instance_of<T: AsValidJObjectAndEnv>(&self) -> bool {
+        T::static_with_jni_type(|ty| {
+            let mut ty = ty.trim_end_matches('\0').to_owned();
+            ty.push('\0');
+            unsafe { Env::from_ptr(self.oae.env).is_instance_of(self.oae.object, &ty) }
+        })
+    }
+
+    /// Uses env.IsInstanceOf to check if the object is a `T`, converting this into a `Local<T>` if it is, or giving it
+    /// back unchanged if it isn't.
+    pub fn try_cast<T: AsValidJObjectAndEnv>(self) -> Result<Local<'env, T>, Self> {
+        if !self.is_instance_of::<T>() { return Err(self); }
+        let local = Local::leak(self);
+        Ok(unsafe { Local::from_env_object(local.oae.env, local.oae.object) })
+    }
 }
 
 impl<'env, Class: AsValidJObjectAndEnv> Deref for Local<'env, Class> {
@@ -66,6 +107,13 @@ impl<'env, Class: AsValidJObjectAndEnv> Deref for Local<'env, Class> {
     }
 }
 
+impl<'env, Class: AsValidJObjectAndEnv> Clone for Local<'env, Class> {
+    fn clone(&self) -> Self {
+        let r = Ref { oae: ObjectAndEnv { object: self.oae.object, env: self.oae.env }, _env: PhantomData, _class: PhantomData };
+        Self::from_ref(&r)
+    }
+}
+
 impl<'env, Class: AsValidJObjectAndEnv> Drop for Local<'env, Class> {
     fn drop(&mut self) {
         let env = self.oae.env as *mut JNIEnv;
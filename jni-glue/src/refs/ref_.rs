@@ -20,6 +20,24 @@ pub struct Ref<'env, Class: AsValidJObjectAndEnv> {
     pub(crate) _class: PhantomData<&'env Class>,
 }
 
+impl<'env, Class: AsValidJObjectAndEnv> Ref<'env, Class> {
+    /// Uses env.NewGlobalRef to create a new [Global] referencing the same object, which may be stored statically and
+    /// shared between threads.
+    ///
+    /// [Global]:   struct.Global.html
+    pub fn to_global(&self) -> Global<Class> {
+        Global::new(self)
+    }
+
+    /// Uses env.MonitorEnter to enter the object's monitor, like a Java `synchronized (object) { ... }` block.  The
+    /// monitor is exited when the returned [MonitorGuard] is dropped.  Required for calling e.g. wait/notify.
+    ///
+    /// [MonitorGuard]: struct.MonitorGuard.html
+    pub fn synchronized<E: ThrowableType>(&self) -> Result<MonitorGuard<'_>, Local<'env, E>> {
+        unsafe { MonitorGuard::enter(self.oae.env as *mut JNIEnv, self.oae.object) }
+    }
+}
+
 impl<'env, Class: AsValidJObjectAndEnv> Deref for Ref<'env, Class> {
     type Target = Class;
     fn deref(&self) -> &Self::Target {
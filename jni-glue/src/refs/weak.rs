@@ -0,0 +1,94 @@
+use super::*;
+
+
+
+/// A [Weak Global](https://www.ibm.com/support/knowledgecenter/en/SSYKE2_8.0.0/com.ibm.java.vm.80.doc/docs/jni_refs.html),
+/// non-owning reference to a Java object (+ &[VM]).
+///
+/// Like [Global], this can be stored statically and shared between threads.  Unlike [Global], this will not keep the
+/// referenced object alive - the garbage collector may reclaim it at any time.  [upgrade] it to a [Local] to use it,
+/// which will return None if the object has already been collected.  As with [Global], dropping a [Weak] after its [VM]
+/// has been unloaded is a no-op.
+///
+/// **Not FFI Safe:**  #\[repr(rust)\], and exact layout is likely to change - depending on exact features used - in the
+/// future.  Specifically, on Android, since we're guaranteed to only have a single ambient [VM], we can likely store the
+/// *const JavaVM in static and/or thread local storage instead of lugging it around in every [Weak].  Of course, there's
+/// no guarantee that's actually an *optimization*...
+///
+/// [VM]:           struct.VM.html
+/// [Global]:       struct.Global.html
+/// [Local]:        struct.Local.html
+/// [Weak]:         struct.Weak.html
+/// [upgrade]:      #method.upgrade
+pub struct Weak<Class: AsValidJObjectAndEnv> {
+    pub(crate) weak:    jweak,
+    pub(crate) gen_vm:  GenVM,
+    pub(crate) pd:      PhantomData<Class>,
+}
+
+unsafe impl<Class: AsValidJObjectAndEnv> Send for Weak<Class> {}
+unsafe impl<Class: AsValidJObjectAndEnv> Sync for Weak<Class> {}
+
+impl<Class: AsValidJObjectAndEnv> Weak<Class> {
+    /// Uses env.NewWeakGlobalRef to create a new weak reference to the same object as `object`.
+    pub(crate) unsafe fn from_env_object(env: &Env, object: jobject) -> Self {
+        let jnienv = env.as_jni_env();
+        let gen_vm = env.get_gen_vm();
+        let weak = (**jnienv).NewWeakGlobalRef.unwrap()(jnienv, object);
+        assert!(!weak.is_null()); // Only sane failure here is an OOM
+        Weak {
+            weak,
+            gen_vm,
+            pd: PhantomData,
+        }
+    }
+
+    /// Uses env.NewLocalRef to get a strong [Local] reference to the object, or None if it's been garbage collected.
+    ///
+    /// [Local]:    struct.Local.html
+    pub fn upgrade<'env>(&self, env: &'env Env) -> Option<Local<'env, Class>> {
+        assert_eq!(self.gen_vm, env.get_gen_vm()); // Soundness check - env *must* belong to the same VM!
+        let jnienv = env.as_jni_env();
+        let object = unsafe { (**jnienv).NewLocalRef.unwrap()(jnienv, self.weak) };
+        if object.is_null() {
+            None
+        } else {
+            Some(unsafe { Local::from_env_object(jnienv, object) })
+        }
+    }
+
+    /// Uses env.IsSameObject to check if the object has been garbage collected.  Note that the result may be out of
+    /// date by the time you act on it - prefer checking the result of [upgrade] instead.
+    ///
+    /// [upgrade]:  #method.upgrade
+    pub fn is_collected(&self, env: &Env) -> bool {
+        assert_eq!(self.gen_vm, env.get_gen_vm()); // Soundness check - env *must* belong to the same VM!
+        let jnienv = env.as_jni_env();
+        unsafe { (**jnienv).IsSameObject.unwrap()(jnienv, self.weak, null_mut()) != JNI_FALSE }
+    }
+}
+
+impl<'env, Class: AsValidJObjectAndEnv> From<&Local<'env, Class>> for Weak<Class> {
+    fn from(local: &Local<'env, Class>) -> Weak<Class> {
+        unsafe { Weak::from_env_object(Env::from_ptr(local.oae.env), local.oae.object) }
+    }
+}
+
+impl<'env, Class: AsValidJObjectAndEnv> From<&Ref<'env, Class>> for Weak<Class> {
+    fn from(r: &Ref<'env, Class>) -> Weak<Class> {
+        unsafe { Weak::from_env_object(Env::from_ptr(r.oae.env), r.oae.object) }
+    }
+}
+
+impl<Class: AsValidJObjectAndEnv> Drop for Weak<Class> {
+    /// Uses env.DeleteWeakGlobalRef, attaching the current thread for the duration if necessary.  Does nothing if the
+    /// [VM](struct.VM.html) this was created with has since been unloaded.
+    fn drop(&mut self) {
+        // If the VM has since been unloaded, so have all of its references - there's nothing left to delete.
+        VMS.read().unwrap().try_use_vm(self.gen_vm, |vm|{
+            let attach = vm.attach(); // Don't leave threads that merely drop a reference attached forever.
+            let env = attach.env().as_jni_env();
+            unsafe { (**env).DeleteWeakGlobalRef.unwrap()(env, self.weak); }
+        });
+    }
+}
@@ -65,6 +65,14 @@ impl<'env> StringChars<'env> {
     pub fn to_string_lossy(&self) -> String {
         self.decode().map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
     }
+
+    /// Returns a new [JavaString] containing these string characters without loss, even if they contain unpaired
+    /// surrogates.
+    /// 
+    /// [JavaString]:               struct.JavaString.html
+    pub fn to_java_string(&self) -> JavaString {
+        JavaString::from_utf16(self.as_u16_slice().iter().cloned())
+    }
 }
 
 impl<'env> Drop for StringChars<'env> {
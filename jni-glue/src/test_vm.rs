@@ -0,0 +1,73 @@
+//! Shared fixtures for tests that need a running JVM.
+//!
+//! Most JVMs only allow a single VM to be created per process, ever - so every test shares one [OwnedVM], created on
+//! first use and never destroyed.  These tests are `#[ignore]`d by default since they need a local JDK:
+//!
+//! ```text
+//! JAVA_HOME=/path/to/jdk cargo test -p jni-glue -- --include-ignored
+//! ```
+//!
+//! [OwnedVM]:  struct.OwnedVM.html
+
+use super::*;
+
+lazy_static! {
+    static ref TEST_VM : OwnedVM = JavaVMBuilder::new()
+        .check_jni()
+        .property("jni.glue.test", "1")
+        .build()
+        .expect("JVM tests require a local JDK - set JAVA_HOME");
+}
+
+/// The VM shared by every test.  Panics if one couldn't be created.
+pub(crate) fn test_vm() -> &'static OwnedVM { &TEST_VM }
+
+/// Run `callback` with the shared test VM's [Env] for the current thread, asserting no exceptions are left pending.
+///
+/// [Env]:  struct.Env.html
+pub(crate) fn with_test_env<R>(callback: impl FnOnce(&Env) -> R) -> R {
+    test_vm().with_env(|env| {
+        let result = callback(env);
+        assert!(!env.exception_check(), "test left an exception pending");
+        result
+    })
+}
+
+/// Take and clear the current thread's pending exception, which must exist.
+pub(crate) fn take_pending(env: &Env) -> JavaException<TestThrowable> {
+    assert!(env.exception_check(), "expected a pending exception");
+    let jnienv = env.as_jni_env();
+    unsafe {
+        let pending = (**jnienv).ExceptionOccurred.unwrap()(jnienv);
+        (**jnienv).ExceptionClear.unwrap()(jnienv);
+        JavaException::from(Local::<TestThrowable>::from_env_object(jnienv, pending))
+    }
+}
+
+/// Create a new, message-less instance of `class` (e.g. "java/lang/IllegalStateException\0".)
+pub(crate) fn new_test_object<'env, T: AsValidJObjectAndEnv>(env: &'env Env, class: &str) -> Local<'env, T> {
+    let (class, ctor) = unsafe { env.require_class_method(class, "<init>\0", "()V\0") };
+    unsafe { env.new_object_a::<T, TestThrowable>(class, ctor, null()) }.unwrap()
+}
+
+#[repr(transparent)] pub(crate) struct TestThrowable(pub(crate) ObjectAndEnv);
+unsafe impl AsValidJObjectAndEnv for TestThrowable {}
+unsafe impl AsJValue for TestThrowable { fn as_jvalue(&self) -> jvalue { jvalue { l: self.0.object } } }
+unsafe impl JniType for TestThrowable { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Throwable\0") } }
+impl ThrowableType for TestThrowable {}
+impl std::fmt::Debug for TestThrowable { fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { write!(f, "Throwable") } }
+
+#[repr(transparent)] pub(crate) struct TestClass(pub(crate) ObjectAndEnv);
+unsafe impl AsValidJObjectAndEnv for TestClass {}
+unsafe impl AsJValue for TestClass { fn as_jvalue(&self) -> jvalue { jvalue { l: self.0.object } } }
+unsafe impl JniType for TestClass { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/Class\0") } }
+
+#[repr(transparent)] pub(crate) struct TestString(pub(crate) ObjectAndEnv);
+unsafe impl AsValidJObjectAndEnv for TestString {}
+unsafe impl AsJValue for TestString { fn as_jvalue(&self) -> jvalue { jvalue { l: self.0.object } } }
+unsafe impl JniType for TestString { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/String\0") } }
+
+#[repr(transparent)] pub(crate) struct TestIntUnaryOperator(pub(crate) ObjectAndEnv);
+unsafe impl AsValidJObjectAndEnv for TestIntUnaryOperator {}
+unsafe impl AsJValue for TestIntUnaryOperator { fn as_jvalue(&self) -> jvalue { jvalue { l: self.0.object } } }
+unsafe impl JniType for TestIntUnaryOperator { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/util/function/IntUnaryOperator\0") } }
@@ -0,0 +1,108 @@
+use super::*;
+
+
+
+/// The result of a native method, which may have left a pending exception for Java to throw when the native method
+/// returns.  FFI safe - #\[repr(transparent)\] - so native methods may return this directly:
+///
+/// ```rust,no_run
+/// # use jni_glue::*;
+/// # use jni_sys::*;
+/// # struct IllegalArgumentException;
+/// # unsafe impl JniType for IllegalArgumentException { fn static_with_jni_type<R>(callback: impl FnOnce(&str) -> R) -> R { callback("java/lang/IllegalArgumentException\0") } }
+/// #[no_mangle] pub extern "system"
+/// fn Java_com_maulingmonkey_example_Math_twice(env: &Env, _class: jclass, value: jint) -> ThrowResult<jint> {
+///     if value < 0 {
+///         return ThrowResult::throw_new::<IllegalArgumentException>(env, "value must be non-negative");
+///     }
+///     ThrowResult::ok(value * 2)
+/// }
+/// ```
+///
+/// When an exception is pending, the actual value returned doesn't matter - Java will ignore it - so a placeholder
+/// from [ThrowReturn] is returned instead.
+///
+/// [ThrowReturn]:  trait.ThrowReturn.html
+#[repr(transparent)]
+pub struct ThrowResult<T: ThrowReturn>(T);
+
+/// Types that native methods can return, with a placeholder value to return when throwing an exception.
+pub trait ThrowReturn {
+    fn placeholder() -> Self;
+}
+
+impl<T: ThrowReturn> ThrowResult<T> {
+    /// Return `value` to Java without throwing.
+    pub fn ok(value: T) -> Self { ThrowResult(value) }
+
+    /// Throw `throwable` (via env.Throw) when returning to Java.
+    pub fn throw<E: ThrowableType>(env: &Env, throwable: &Ref<'_, E>) -> Self {
+        env.throw(throwable);
+        ThrowResult(T::placeholder())
+    }
+
+    /// Throw a new `E` (via env.ThrowNew) with `message` when returning to Java.  Panics if `E` isn't a
+    /// java.lang.Throwable.
+    pub fn throw_new<E: JniType>(env: &Env, message: &str) -> Self {
+        env.throw_new::<E>(message);
+        ThrowResult(T::placeholder())
+    }
+
+    /// The value that will be returned to Java.
+    pub fn into_inner(self) -> T { self.0 }
+}
+
+impl<'env, T: ThrowReturn, E: ThrowableType> From<Result<T, Local<'env, E>>> for ThrowResult<T> {
+    fn from(result: Result<T, Local<'env, E>>) -> Self {
+        match result {
+            Ok(value)       => ThrowResult::ok(value),
+            Err(throwable)  => {
+                let env = throwable.oae.env as *mut JNIEnv;
+                let result = unsafe { (**env).Throw.unwrap()(env, throwable.oae.object) };
+                debug_assert_eq!(result, JNI_OK);
+                ThrowResult(T::placeholder())
+            },
+        }
+    }
+}
+
+impl<T: ThrowReturn, E: ThrowableType> From<Result<T, JavaException<E>>> for ThrowResult<T> {
+    fn from(result: Result<T, JavaException<E>>) -> Self {
+        match result {
+            Ok(value)       => ThrowResult::ok(value),
+            Err(exception)  => {
+                let global = exception.throwable();
+                VMS.read().unwrap().use_vm(global.gen_vm, |vm| {
+                    let attach = vm.attach(); // Native methods are already attached - this won't outlive the call.
+                    ThrowResult::throw(attach.env(), &global.with(attach.env()))
+                })
+            },
+        }
+    }
+}
+
+impl ThrowReturn for ()         { fn placeholder() -> Self {} }
+impl ThrowReturn for jboolean   { fn placeholder() -> Self { JNI_FALSE } }
+impl ThrowReturn for jbyte      { fn placeholder() -> Self { 0 } }
+impl ThrowReturn for jni_sys::jchar { fn placeholder() -> Self { 0 } }
+impl ThrowReturn for jchar      { fn placeholder() -> Self { jchar(0) } }
+impl ThrowReturn for jshort     { fn placeholder() -> Self { 0 } }
+impl ThrowReturn for jint       { fn placeholder() -> Self { 0 } }
+impl ThrowReturn for jlong      { fn placeholder() -> Self { 0 } }
+impl ThrowReturn for jfloat     { fn placeholder() -> Self { 0.0 } }
+impl ThrowReturn for jdouble    { fn placeholder() -> Self { 0.0 } }
+impl ThrowReturn for jobject    { fn placeholder() -> Self { null_mut() } }
+
+#[test] #[ignore = "requires a local JDK, see test_vm.rs"] fn throw_result_pending() {
+    // Native methods should be able to leave exceptions pending for Java to throw.
+    with_test_env(|env|{
+        assert!(!env.exception_check());
+        assert_eq!(ThrowResult::<jint>::throw_new::<TestThrowable>(env, "thrown").into_inner(), 0);
+        let thrown = take_pending(env);
+        assert_eq!(thrown.to_string(), "java.lang.Throwable: thrown");
+        env.throw(&thrown.throwable().with(env));
+        assert_eq!(take_pending(env).to_string(), "java.lang.Throwable: thrown");
+        assert!(ThrowResult::<jobject>::from(Result::<jobject, _>::Err(thrown)).into_inner().is_null());
+        assert_eq!(take_pending(env).to_string(), "java.lang.Throwable: thrown");
+    });
+}
@@ -3,9 +3,8 @@ use super::*;
 /// FFI: Use **&VM** instead of *const JavaVM.  This represents a global, process-wide Java exection environment.
 /// 
 /// On Android, there is only one VM per-process, although on desktop it's possible (if rare) to have multiple VMs
-/// within the same process.  While this library does not yet support having multiple VMs active simultaniously, please
-/// don't hesitate to [file an issue](https://github.com/MaulingMonkey/jni-bindgen/issues/new) if this is an important
-/// use case for you.
+/// within the same process.  By default this library assumes there is only ever one VM active at a time - enable the
+/// "multi-vm" feature if you need to have multiple VMs active simultaniously.
 ///
 /// This is a "safe" alternative to jni_sys::JavaVM raw pointers, with the following caveats:
 /// 
@@ -23,22 +22,79 @@ impl VM {
     pub fn as_java_vm(&self) -> *const JavaVM { &self.0 }
     pub unsafe fn from_jni_local(vm: &JavaVM) -> &VM { &*(vm as *const JavaVM as *const VM) }
 
+    /// Run `callback` with the current thread's [Env], attaching the current thread if necessary.
+    ///
+    /// **Note:**  If the current thread wasn't already attached, it will remain attached after this returns.  See
+    /// [with_env_attachment] to find out if that happened, or [attach] to scope the attachment instead.
+    ///
+    /// [Env]:                  struct.Env.html
+    /// [with_env_attachment]:  #method.with_env_attachment
+    /// [attach]:               #method.attach
     pub fn with_env<F, R>(&self, callback: F) -> R
     where
         F: FnOnce(&Env) -> R,
+    {
+        self.with_env_attachment(|env, _attachment| callback(env))
+    }
+
+    /// Run `callback` with the current thread's [Env], attaching the current thread if necessary, and report if the
+    /// thread was attached by this call.
+    ///
+    /// [Env]:                  struct.Env.html
+    pub fn with_env_attachment<F, R>(&self, callback: F) -> R
+    where
+        F: FnOnce(&Env, Attachment) -> R,
     {
         let java_vm = self.as_java_vm() as *mut JavaVM;
         let mut env = null_mut();
         match unsafe { (**java_vm).GetEnv.unwrap()(java_vm, &mut env, JNI_VERSION_1_2) } {
-            JNI_OK => callback(unsafe { Env::from_jni_void_ref(&env) }),
+            JNI_OK => callback(unsafe { Env::from_jni_void_ref(&env) }, Attachment::Existing),
             JNI_EDETACHED => match unsafe { (**java_vm).AttachCurrentThread.unwrap()(java_vm, &mut env, null_mut()) } {
-                JNI_OK => callback(unsafe { Env::from_jni_void_ref(&env) }),
+                JNI_OK => callback(unsafe { Env::from_jni_void_ref(&env) }, Attachment::New),
                 unexpected => panic!("AttachCurrentThread returned unknown error: {}", unexpected),
             },
             JNI_EVERSION => panic!("GetEnv returned JNI_EVERSION"),
             unexpected => panic!("GetEnv returned unknown error: {}", unexpected),
         }
     }
+
+    /// Attach the current thread if it isn't already attached.  The returned [AttachGuard] will detach the thread when
+    /// dropped - but only if it was the one to attach it.
+    ///
+    /// [AttachGuard]:  struct.AttachGuard.html
+    pub fn attach(&self) -> AttachGuard<'_> {
+        unsafe { AttachGuard::new(self, false, None, null_mut()) }
+    }
+
+    /// Attach the current thread as a daemon thread if it isn't already attached, optionally naming the resulting
+    /// java.lang.Thread.  Daemon threads won't prevent the VM from shutting down.  The returned [AttachGuard] will detach
+    /// the thread when dropped - but only if it was the one to attach it.
+    ///
+    /// [AttachGuard]:  struct.AttachGuard.html
+    pub fn attach_as_daemon(&self, name: Option<&str>) -> AttachGuard<'_> {
+        unsafe { AttachGuard::new(self, true, name, null_mut()) }
+    }
+
+    /// Like [attach_as_daemon], but also specifies the java.lang.ThreadGroup the resulting java.lang.Thread should
+    /// belong to.
+    ///
+    /// [attach_as_daemon]: #method.attach_as_daemon
+    pub fn attach_as_daemon_in_group<Group: AsValidJObjectAndEnv>(&self, name: Option<&str>, group: &Global<Group>) -> AttachGuard<'_> {
+        assert_eq!(group.gen_vm.vm, self.as_java_vm()); // Soundness check - group *must* belong to the same VM!
+        unsafe { AttachGuard::new(self, true, name, group.global) }
+    }
+}
+
+/// Reports whether or not the current thread was attached to the VM by a call such as [with_env_attachment].
+///
+/// [with_env_attachment]:  struct.VM.html#method.with_env_attachment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Attachment {
+    /// The thread was already attached to the VM (e.g. a thread created by Java, or previously attached.)
+    Existing,
+
+    /// The thread was attached to the VM by this call.
+    New,
 }
 
 unsafe impl Send for VM {}
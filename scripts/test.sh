@@ -16,6 +16,8 @@ cd ..
 print_run rustup target add aarch64-linux-android armv7-linux-androideabi i686-linux-android x86_64-linux-android
 print_run cargo build --all --release
 print_run cargo test  --all --release
+print_run cargo test  --package jni-glue --all-features --release --lib -- --include-ignored # JVM tests, requires JAVA_HOME
+print_run cargo test  --package jni-glue-macros --release --test native -- --include-ignored
 pushd jni-android-sys
 print_run ../target/release/jni-bindgen --android-api-levels=7-28 generate
 print_run cargo build --features "all api-level-28 force-define"